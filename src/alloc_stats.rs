@@ -0,0 +1,156 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+};
+
+/// Heap usage of one run of a part.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes requested by all allocations.
+    pub bytes: u64,
+    /// Largest number of bytes allocated during the run and not freed at the same time.
+    pub peak_bytes: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "allocs: {}, allocated: {}, peak: {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+// counters are kept per thread so parts solved in parallel don't see each other's allocations.
+// `const` thread locals without destructors are safe to use from inside the allocator.
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // `try_with` fails while the thread is being torn down, those allocations are not counted.
+    let _ = LIVE.try_with(|live| {
+        let current = live.get() + allocated as i64 - freed as i64;
+        live.set(current);
+        PEAK.with(|peak| peak.set(peak.get().max(current)));
+        if allocated > 0 {
+            ALLOCATIONS.with(|n| n.set(n.get() + 1));
+            BYTES.with(|bytes| bytes.set(bytes.get() + allocated as u64));
+        }
+    });
+}
+
+/// Wraps the system allocator to count the allocations of the current thread.
+/// Installed as the global allocator by the `alloc-stats` feature.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// `true` if the `alloc-stats` feature installed [`CountingAllocator`].
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Runs `f` and counts the allocations it makes on the current thread.
+/// Returns `None` for the statistics if the counting allocator is not installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    let start_allocations = ALLOCATIONS.with(Cell::get);
+    let start_bytes = BYTES.with(Cell::get);
+    let start_live = LIVE.with(Cell::get);
+    PEAK.with(|peak| peak.set(start_live));
+
+    let result = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.with(Cell::get) - start_allocations,
+        bytes: BYTES.with(Cell::get) - start_bytes,
+        peak_bytes: (PEAK.with(Cell::get) - start_live).max(0) as u64,
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn test_measure() {
+        let (_, stats) = measure(|| {
+            let a = vec![0u8; 1000];
+            drop(a);
+            let b = vec![0u8; 500];
+            b.len()
+        });
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 1500);
+        assert_eq!(stats.peak_bytes, 1000);
+    }
+}
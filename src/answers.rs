@@ -0,0 +1,204 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ANSI_ITALIC, ANSI_RESET};
+use std::{fs, io};
+
+pub fn get_answers_path(year: u16, day: u8) -> String {
+    format!("src/answers/{year}/{day:02}.toml")
+}
+
+/// Accepted answers for the real input of a day, stored in `src/answers/YYYY/DD.toml`:
+///
+/// ```toml
+/// part_one = "24000"
+/// part_two = "45000"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    /// Loads the answers of a day. A missing file means no answer is known yet.
+    pub fn load(year: u16, day: u8) -> Result<Self, String> {
+        let path = get_answers_path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents).map_err(|e| format!("{path}: {e}")),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{path}: {e}")),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> io::Result<()> {
+        fs::create_dir_all(format!("src/answers/{year}"))?;
+        fs::write(get_answers_path(year, day), self.to_toml())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.to_string()),
+            2 => self.part_two = Some(answer.to_string()),
+            _ => {}
+        }
+    }
+
+    /// Parses the subset of TOML used by answer files: `key = value` pairs where
+    /// values are integers, basic strings or multi-line basic strings.
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        let mut lines = contents.lines().enumerate();
+
+        while let Some((i, line)) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+            let value = value.trim();
+
+            let value = if let Some(rest) = value.strip_prefix("\"\"\"") {
+                // a newline right after the opening delimiter is trimmed.
+                let mut raw = String::from(rest);
+                while !raw.ends_with("\"\"\"") {
+                    let (_, next) = lines
+                        .next()
+                        .ok_or_else(|| format!("line {}: unterminated string", i + 1))?;
+                    if !raw.is_empty() {
+                        raw.push('\n');
+                    }
+                    raw.push_str(next);
+                }
+                raw.truncate(raw.len() - 3);
+                unescape(&raw).map_err(|e| format!("line {}: {e}", i + 1))?
+            } else if let Some(rest) = value.strip_prefix('"') {
+                let raw = rest
+                    .strip_suffix('"')
+                    .ok_or_else(|| format!("line {}: unterminated string", i + 1))?;
+                unescape(raw).map_err(|e| format!("line {}: {e}", i + 1))?
+            } else if value.parse::<i64>().is_ok() {
+                value.to_string()
+            } else {
+                return Err(format!("line {}: expected a string or integer", i + 1));
+            };
+
+            match key.trim() {
+                "part_one" => answers.part_one = Some(value),
+                "part_two" => answers.part_two = Some(value),
+                key => return Err(format!("line {}: unknown key {key:?}", i + 1)),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn to_toml(&self) -> String {
+        [("part_one", &self.part_one), ("part_two", &self.part_two)]
+            .iter()
+            .filter_map(|(key, value)| {
+                let value = value.as_ref()?;
+                Some(if value.contains('\n') {
+                    format!("{key} = \"\"\"\n{}\"\"\"\n", escape(value))
+                } else {
+                    format!("{key} = \"{}\"\n", escape(value))
+                })
+            })
+            .collect()
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn unescape(value: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('"') => unescaped.push('"'),
+            Some('\\') => unescaped.push('\\'),
+            c => {
+                return Err(format!(
+                    "unsupported escape sequence \\{}",
+                    c.unwrap_or(' ')
+                ))
+            }
+        }
+    }
+    Ok(unescaped)
+}
+
+/// `Some(true)` if `answer` matches the accepted answer, `None` if none is known.
+pub fn verify(answer: &str, expected: Option<&str>) -> Option<bool> {
+    expected.map(|expected| answer.trim() == expected.trim())
+}
+
+/// Suffix printed after an answer: ✓ if it matches the accepted answer, ✗ and the accepted answer otherwise.
+pub fn mark(answer: &str, expected: Option<&str>) -> String {
+    match (verify(answer, expected), expected) {
+        (Some(true), _) => " ✓".into(),
+        (Some(false), Some(expected)) if expected.contains('\n') => {
+            format!(" ✗ {ANSI_ITALIC}(expected:\n{expected}){ANSI_RESET}")
+        }
+        (Some(false), Some(expected)) => {
+            format!(" ✗ {ANSI_ITALIC}(expected: {expected}){ANSI_RESET}")
+        }
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("# day 1\npart_one = \"24000\"\npart_two = 45000\n").unwrap();
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), Some("45000"));
+
+        let answers = Answers::parse("part_two = \"\"\"\n#.\n.#\"\"\"\n").unwrap();
+        assert_eq!(answers.part_one, None);
+        assert_eq!(answers.get(2), Some("#.\n.#"));
+
+        assert!(Answers::parse("part_three = 1").is_err());
+        assert!(Answers::parse("part_one = abc").is_err());
+        assert!(Answers::parse("part_one = \"abc").is_err());
+    }
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let answers = Answers {
+            part_one: Some("C\"M\\Z".into()),
+            part_two: Some("##..\n..##".into()),
+        };
+        assert_eq!(Answers::parse(&answers.to_toml()), Ok(answers));
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(verify("1", Some("1")), Some(true));
+        assert_eq!(verify("1", Some("2")), Some(false));
+        assert_eq!(verify("1", None), None);
+    }
+}
@@ -0,0 +1,440 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Overrides the base url, e.g. to point the client at a local stand-in server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Session token. Takes precedence over the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Session file in the home directory, shared with aoc-cli.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+/// How to reach whoever runs the client, e.g. the url of your repository or an email address.
+/// Sent with every request, defaults to the `repository` of `Cargo.toml`.
+pub const CONTACT_ENV: &str = "AOC_CONTACT";
+
+/// Identifies the client and, if known, who to contact about its requests.
+fn user_agent() -> String {
+    let version = env!("CARGO_PKG_VERSION");
+    let contact = env::var(CONTACT_ENV).unwrap_or_else(|_| env!("CARGO_PKG_REPOSITORY").into());
+    match contact.trim() {
+        "" => format!("advent_of_code/{version}"),
+        contact => format!("advent_of_code/{version} (+{contact})"),
+    }
+}
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    Request(String),
+    BadStatus(u16),
+    IoError,
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session token found. Set {SESSION_ENV} or create ~/{SESSION_FILE}."
+            ),
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(404) => {
+                write!(f, "puzzle not found. Is it unlocked yet?")
+            }
+            AocClientError::BadStatus(400 | 500) => {
+                write!(
+                    f,
+                    "request was rejected. Your session token may have expired."
+                )
+            }
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::IoError => write!(f, "could not write output files to file system."),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(&user_agent())
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Configures the client from [`BASE_URL_ENV`], [`SESSION_ENV`] and the session file.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = read_session().ok_or(AocClientError::SessionNotFound)?;
+        Ok(Self::new(&base_url, &session))
+    }
+
+    pub fn input(&self, day: u8, year: u16) -> Result<String, AocClientError> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// Fetches the puzzle page and converts its description to markdown.
+    pub fn puzzle(&self, day: u8, year: u16) -> Result<String, AocClientError> {
+        let html = self.get(&format!("/{year}/day/{day}"))?;
+        Ok(puzzle_to_markdown(&html))
+    }
+
+    /// Submits an answer and returns the html of the response page.
+    /// See [`crate::submit::parse_response`] to interpret it.
+    pub fn submit(
+        &self,
+        day: u8,
+        year: u16,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", answer)]);
+
+        Self::into_body(response)
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        let url = format!("{}{}", self.base_url, path);
+
+        if cfg!(debug_assertions) {
+            println!("Fetching {url}");
+        }
+
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        Self::into_body(response)
+    }
+
+    fn into_body(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| AocClientError::Request(e.to_string())),
+            Err(ureq::Error::Status(status, _)) => Err(AocClientError::BadStatus(status)),
+            Err(e) => Err(AocClientError::Request(e.to_string())),
+        }
+    }
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Some(session);
+        }
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let session = fs::read_to_string(PathBuf::from(home).join(SESSION_FILE)).ok()?;
+    (!session.trim().is_empty()).then_some(session)
+}
+
+/// Year of the most recent event: the current year in December, otherwise the previous one.
+pub fn current_event_year() -> u16 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let (year, month) = civil_from_days((secs / 86_400) as i64);
+    if month == 12 {
+        year as u16
+    } else {
+        year as u16 - 1
+    }
+}
+
+/// Converts days since 1970-01-01 to `(year, month)`.
+/// see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month)
+}
+
+/// Writes input and puzzle description of a day to `src/inputs/YYYY` and `src/puzzles/YYYY`.
+pub fn download(client: &AocClient, day: u8, year: u16) -> Result<(), AocClientError> {
+    let input_path = crate::aoc_cli::get_input_path(year, day);
+    let puzzle_path = crate::aoc_cli::get_puzzle_path(year, day);
+
+    let input = client.input(day, year)?;
+    let puzzle = client.puzzle(day, year)?;
+
+    fs::create_dir_all(format!("src/inputs/{year}")).map_err(|_| AocClientError::IoError)?;
+    fs::create_dir_all(format!("src/puzzles/{year}")).map_err(|_| AocClientError::IoError)?;
+    fs::write(&input_path, input).map_err(|_| AocClientError::IoError)?;
+    fs::write(&puzzle_path, puzzle).map_err(|_| AocClientError::IoError)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Converts the `<article>` elements of a puzzle page to markdown.
+/// Only handles the handful of tags that appear in puzzle descriptions.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    // emphasized inline code is written as <code><em>..</em></code>. markdown can't emphasize
+    // inside code spans, so swap the tags to emit *`..`* instead.
+    let html = html
+        .replace("<code><em>", "<em><code>")
+        .replace("</em></code>", "</code></em>");
+
+    let mut markdown = String::new();
+    let mut rest = html.as_str();
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        let article = &rest[start..start + end];
+        article_to_markdown(article, &mut markdown);
+        rest = &rest[start + end..];
+    }
+
+    markdown.trim_end().to_string() + "\n"
+}
+
+fn article_to_markdown(article: &str, out: &mut String) {
+    let mut in_pre = false;
+    let mut link: Option<String> = None;
+    let mut rest = article;
+
+    while !rest.is_empty() {
+        let Some(open) = rest.find('<') else {
+            push_text(out, rest, in_pre);
+            break;
+        };
+
+        push_text(out, &rest[..open], in_pre);
+
+        let Some(close) = rest[open..].find('>') else {
+            break;
+        };
+        let tag = &rest[open + 1..open + close];
+        rest = &rest[open + close + 1..];
+
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+        let closing = tag.starts_with('/');
+
+        match (name, closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p" | "ul", true) => end_block(out),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```");
+                end_block(out);
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("a", false) => {
+                link = attribute(tag, "href");
+                out.push('[');
+            }
+            ("a", true) => {
+                out.push(']');
+                out.push_str(&format!("({})", link.take().unwrap_or_default()));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn end_block(out: &mut String) {
+    out.truncate(out.trim_end_matches([' ', '\n']).len());
+    out.push_str("\n\n");
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+    if in_pre {
+        out.push_str(&text);
+        return;
+    }
+
+    let mut last_was_space = out.ends_with([' ', '\n']) || out.is_empty();
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_was_space {
+                out.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            out.push(c);
+            last_was_space = false;
+        }
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves `body` to a single request and returns the request head that was received.
+    fn serve_once(status: &str, body: &str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            head
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        let client = AocClient::new(&base_url, "secret\n");
+
+        assert_eq!(client.input(1, 2022).unwrap(), "1000\n2000\n");
+
+        let head = server.join().unwrap();
+        assert!(head.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(head.contains("Cookie: session=secret\r\n"));
+        assert!(head.contains("User-Agent: advent_of_code/"));
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = serve_once("200 OK", "<p>That's the right answer!</p>");
+        let client = AocClient::new(&base_url, "secret");
+
+        let response = client.submit(1, 2022, 2, "45000").unwrap();
+        assert_eq!(response, "<p>That's the right answer!</p>");
+
+        let head = server.join().unwrap();
+        assert!(head.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(head.contains("Content-Type: application/x-www-form-urlencoded\r\n"));
+    }
+
+    #[test]
+    fn test_bad_status() {
+        let (base_url, server) = serve_once("404 Not Found", "");
+        let client = AocClient::new(&base_url, "secret");
+
+        assert!(matches!(
+            client.input(25, 2022),
+            Err(AocClientError::BadStatus(404))
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_puzzle_to_markdown() {
+        let html = r#"<main><article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <a href="/2022/day/1/input">Calories</a>
+contained by the various meals.</p>
+<pre><code>1000
+<em>2000</em>
+</code></pre>
+<ul><li>The first Elf is carrying <code>6000</code> Calories.</li></ul>
+<p>This Elf is carrying <code><em>24000</em></code> Calories &amp; <em>more</em>.</p>
+</article><p>Your puzzle answer was <code>1</code>.</p></main>"#;
+
+        assert_eq!(
+            puzzle_to_markdown(html),
+            "## --- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of [Calories](/2022/day/1/input) contained by the various meals.
+
+```
+1000
+2000
+```
+
+- The first Elf is carrying `6000` Calories.
+
+This Elf is carrying *`24000`* Calories & *more*.
+"
+        );
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1));
+        assert_eq!(civil_from_days(19_327), (2022, 12));
+        assert_eq!(civil_from_days(19_358), (2023, 1));
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{alloc_stats::AllocStats, answers, PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{fmt::Display, time::Duration};
+
+/// Summary of the timings collected over a benchmark run.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+        // nearest-rank percentile.
+        let p95 = sorted[(len * 95).div_ceil(100) - 1];
+
+        let nanos = sorted.iter().map(|d| d.as_nanos() as f64);
+        let mean = nanos.clone().sum::<f64>() / len as f64;
+        let variance = nanos.map(|n| (n - mean).powi(2)).sum::<f64>() / len as f64;
+
+        Some(Self {
+            samples: len,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            p95,
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min: {:.2?}, median: {:.2?}, mean: {:.2?}, p95: {:.2?}, std dev: {:.2?}, runs: {}",
+            self.min, self.median, self.mean, self.p95, self.std_dev, self.samples
+        )
+    }
+}
+
+/// Warmup runs used when `--warmup` is not given: a tenth of the measured iterations.
+pub fn default_warmup(iterations: u32) -> u32 {
+    (iterations / 10).max(1)
+}
+
+/// Calls `solver` `warmup` times without measuring, then `iterations` times while collecting timings.
+/// Returns the result of the last measured call.
+pub fn bench(
+    solver: impl Fn(&str) -> PartResult,
+    input: &str,
+    iterations: u32,
+    warmup: u32,
+) -> (PartResult, Option<Stats>) {
+    for _ in 0..warmup {
+        solver(input);
+    }
+
+    let mut samples = Vec::with_capacity(iterations as usize);
+    let mut result = solver(input);
+    samples.push(result.elapsed);
+
+    for _ in 1..iterations {
+        result = solver(input);
+        samples.push(result.elapsed);
+    }
+
+    (result, Stats::from_samples(&samples))
+}
+
+pub fn print_stats(
+    part: u8,
+    answer: &str,
+    stats: &Stats,
+    allocs: Option<&AllocStats>,
+    expected: Option<&str>,
+) {
+    println!("🎄 {ANSI_BOLD}Part {part}{ANSI_RESET} 🎄");
+    println!(
+        "{answer} {ANSI_ITALIC}({stats}{}){ANSI_RESET}{}",
+        crate::format_allocs(allocs),
+        answers::mark(answer, expected)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_micros(*v)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 4, 2, 3])).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1414));
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let samples = micros(&(1..=20).collect::<Vec<_>>());
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(10_500));
+        assert_eq!(stats.p95, Duration::from_micros(19));
+    }
+
+    #[test]
+    fn test_stats_no_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -3,18 +3,21 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use advent_of_code::aoc_cli;
+use advent_of_code::aoc_client::{self, AocClient};
 use std::process;
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
     })
 }
 
@@ -27,11 +30,25 @@ fn main() {
         }
     };
 
+    let year = args.year;
+
+    let result = AocClient::from_env().and_then(|client| {
+        println!("Downloading input & puzzle for day {}, {year}...", args.day);
+        aoc_client::download(&client, args.day, year)
+    });
+
+    let Err(e) = result else {
+        return;
+    };
+
+    eprintln!("Failed to download puzzle: {e}");
+
     if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
+    eprintln!("Falling back to aoc-cli...");
+
     match aoc_cli::download(args.day, args.year) {
         Ok(cmd_output) => {
             if !cmd_output.status.success() {
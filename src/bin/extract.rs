@@ -0,0 +1,97 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::aoc_cli;
+use advent_of_code::extract::{self, Example};
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// Overwrite an example file that is not empty.
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        force: args.contains("--force"),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            eprintln!("Need to specify a day (as integer). example: `cargo extract 7`");
+            process::exit(1);
+        }
+    };
+
+    let (day, year) = (args.day, args.year);
+    let puzzle_path = aoc_cli::get_puzzle_path(year, day);
+    let example_path = format!("src/examples/{year}/{day:02}.txt");
+    let module_path = format!("src/solutions/year{year}/day{day:02}.rs");
+
+    let puzzle = match fs::read_to_string(&puzzle_path) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("Failed to read \"{puzzle_path}\": {e}");
+            eprintln!("Download the puzzle first with `cargo download {day} --year {year}`.");
+            process::exit(1);
+        }
+    };
+
+    let example = Example::from_puzzle(&puzzle);
+
+    match &example.input {
+        Some(input) => {
+            let existing = fs::read_to_string(&example_path).unwrap_or_default();
+            if !existing.trim().is_empty() && !args.force {
+                println!("Kept existing example file \"{example_path}\", pass `--force` to overwrite it.");
+            } else if let Err(e) = fs::create_dir_all(format!("src/examples/{year}"))
+                .and_then(|_| fs::write(&example_path, input))
+            {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            } else {
+                println!("Wrote example input to \"{example_path}\"");
+            }
+        }
+        None => println!("Found no example input in \"{puzzle_path}\"."),
+    }
+
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        println!("No module at \"{module_path}\", run `cargo scaffold {day} --year {year}` to create it.");
+        return;
+    };
+
+    for part in [1, 2] {
+        let Some(answer) = example.answer(part) else {
+            println!("Found no example answer for part {part}.");
+            continue;
+        };
+
+        match extract::fill_assertion(&module, day, part, answer) {
+            Some(filled) => {
+                module = filled;
+                println!("Expecting {answer:?} in the test of part {part}.");
+                if answer.parse::<i64>().is_err() {
+                    println!("Note: the answer is not a number, change the answer type of part {part} to `String`.");
+                }
+            }
+            None => println!("Kept the test of part {part}, it no longer asserts `None`."),
+        }
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("Failed to write module file: {e}");
+        process::exit(1);
+    }
+}
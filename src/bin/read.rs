@@ -3,21 +3,36 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::aoc_client::AocClient;
+use advent_of_code::markdown;
+use std::{env, fs, process};
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
+    /// Fetch the puzzle even if it was downloaded before.
+    remote: bool,
+    width: Option<usize>,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        remote: args.contains("--remote"),
+        width: args.opt_value_from_str(["-w", "--width"])?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
+fn print_puzzle(puzzle: &str, args: &Args) {
+    let width = args.width.unwrap_or_else(markdown::terminal_width);
+    let color = env::var_os("NO_COLOR").is_none();
+    print!("{}", markdown::render(puzzle, width, color));
+}
+
 fn main() {
     let args = match parse_args() {
         Ok(args) => args,
@@ -27,11 +42,33 @@ fn main() {
         }
     };
 
+    if !args.remote {
+        if let Ok(puzzle) = fs::read_to_string(aoc_cli::get_puzzle_path(args.year, args.day)) {
+            print_puzzle(&puzzle, &args);
+            return;
+        }
+    }
+
+    let year = args.year;
+
+    let result = AocClient::from_env().and_then(|client| client.puzzle(args.day, year));
+
+    let e = match result {
+        Ok(puzzle) => {
+            print_puzzle(&puzzle, &args);
+            return;
+        }
+        Err(e) => e,
+    };
+
+    eprintln!("Failed to fetch puzzle: {e}");
+
     if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
+    eprintln!("Falling back to aoc-cli...");
+
     match aoc_cli::read(args.day, args.year) {
         Ok(cmd_output) => {
             if !cmd_output.status.success() {
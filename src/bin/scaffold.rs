@@ -3,46 +3,86 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
+/// Used if neither `--template` is passed nor `templates/default.rs` exists, e.g. after it was deleted.
+/// Embedded from that file at compile time, so there is a single copy of the default template.
+const MODULE_TEMPLATE: &str = include_str!("../../templates/default.rs");
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+const BIN_TEMPLATE: &str = r###"fn main() {
+    advent_of_code::run_day({day});
 }
+"###;
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+const YEAR_MODULE_TEMPLATE: &str = r###"/*
+ * Registry of solved days of {year}.
+ * `cargo scaffold` adds new days here; the order of `DAYS` is the order `cargo all` runs them in.
+ */
+use crate::Day;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub mod day{day_padded};
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
+pub const DAYS: &[Day] = &[Day::of::<day{day_padded}::Day{day_padded}>()];
+"###;
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+const REGISTRY_PATH: &str = "src/solutions/mod.rs";
+const TEMPLATES_DIR: &str = "templates";
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// Name of a module template in `templates/`.
+    template: Option<String>,
+    answer_type: String,
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        template: args.opt_value_from_str(["-t", "--template"])?,
+        answer_type: args
+            .opt_value_from_str("--answer-type")?
+            .unwrap_or_else(|| "u32".into()),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Names of the templates in `templates/`, sorted.
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "rs").then(|| path.file_stem()?.to_str().map(String::from))?
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Loads the module template `templates/<name>.rs`. Without a name, `templates/default.rs` is used
+/// if it exists and the built-in template otherwise.
+fn load_template(name: Option<&str>) -> Result<String, String> {
+    let path = format!("{TEMPLATES_DIR}/{}.rs", name.unwrap_or("default"));
+    match (fs::read_to_string(&path), name) {
+        (Ok(template), _) => Ok(template),
+        (Err(e), _) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(format!("could not read \"{path}\": {e}"))
+        }
+        (Err(_), None) => Ok(MODULE_TEMPLATE.into()),
+        (Err(_), Some(name)) => Err(format!(
+            "template \"{name}\" does not exist. Available templates in \"{TEMPLATES_DIR}\": {}",
+            available_templates().join(", ")
+        )),
+    }
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
@@ -50,23 +90,119 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+fn fill_template(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{day_padded}", &format!("{day:02}"))
+        .replace("{day}", &day.to_string())
+        .replace("{year}", &year.to_string())
+}
+
+/// Adds the day's module and its `Day::of` entry to the registry of its year in `src/solutions/yearYYYY/mod.rs`.
+/// A year that has no registry yet gets one, which is added to `src/solutions/mod.rs`.
+fn register_module(year: u16, day: u8) -> Result<(), std::io::Error> {
+    let day_padded = format!("{day:02}");
+    let year_registry_path = format!("src/solutions/year{year}/mod.rs");
+
+    if !Path::new(&year_registry_path).exists() {
+        fs::create_dir_all(format!("src/solutions/year{year}"))?;
+        fs::write(
+            &year_registry_path,
+            fill_template(YEAR_MODULE_TEMPLATE, year, day),
+        )?;
+        return register(
+            REGISTRY_PATH,
+            &format!("pub mod year{year};"),
+            &format!("Year::of({year}, year{year}::DAYS)"),
+        );
+    }
+
+    register(
+        &year_registry_path,
+        &format!("pub mod day{day_padded};"),
+        &format!("Day::of::<day{day_padded}::Day{day_padded}>()"),
+    )
+}
+
+/// Inserts `module_line` after the last `pub mod` of a registry and `entry` at the end of its list.
+fn register(path: &str, module_line: &str, entry: &str) -> Result<(), std::io::Error> {
+    let registry = fs::read_to_string(path)?;
+    let module_line = module_line.to_string();
+    let entry_line = format!("    {entry},");
+
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+
+    if !lines.contains(&module_line) {
+        let index = lines
+            .iter()
+            .rposition(|line| line.starts_with("pub mod "))
+            .map_or(lines.len(), |i| i + 1);
+        lines.insert(index, module_line);
+    }
+
+    if !lines.contains(&entry_line) && !registry.contains(&format!("&[{entry}];")) {
+        let index = lines
+            .iter()
+            .rposition(|line| line.ends_with("];"))
+            .ok_or_else(|| {
+                std::io::Error::other(format!("could not find the end of the list in {path}"))
+            })?;
+
+        // rustfmt puts a list with a single entry on one line: `pub const DAYS: &[Day] = &[entry];`
+        match lines[index].rsplit_once("&[") {
+            Some((head, single)) if lines[index] != "];" => {
+                let single = format!("    {},", single.trim_end_matches("];"));
+                let head = format!("{head}&[");
+                lines.splice(index..=index, [head, single, entry_line, "];".into()]);
+            }
+            _ => lines.insert(index, entry_line),
+        }
+    }
+
+    fs::write(path, lines.join("\n") + "\n")
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
+    let (day, year) = (args.day, args.year);
     let day_padded = format!("{day:02}");
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+    let template = match load_template(args.template.as_deref()) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let input_path = format!("src/inputs/{year}/{day_padded}.txt");
+    let example_path = format!("src/examples/{year}/{day_padded}.txt");
+    let module_path = format!("src/solutions/year{year}/day{day_padded}.rs");
+    let bin_path = format!("src/bin/{day_padded}.rs");
+
+    for dir in [
+        format!("src/inputs/{year}"),
+        format!("src/examples/{year}"),
+        format!("src/solutions/year{year}"),
+    ] {
+        if let Err(e) = fs::create_dir_all(&dir) {
+            eprintln!("Failed to create directory \"{dir}\": {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,7 +212,9 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    let module = fill_template(&template, year, day).replace("{answer_type}", &args.answer_type);
+
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -86,6 +224,37 @@ fn main() {
         }
     }
 
+    match register_module(year, day) {
+        Ok(_) => {
+            println!("Registered module in \"src/solutions/year{year}/mod.rs\"");
+        }
+        Err(e) => {
+            eprintln!("Failed to register module: {e}");
+            process::exit(1);
+        }
+    }
+
+    // binaries are shared by all years and pick theirs with `--year`.
+    if !Path::new(&bin_path).exists() {
+        let mut file = match safe_create_file(&bin_path) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("Failed to create binary file: {e}");
+                process::exit(1);
+            }
+        };
+
+        match file.write_all(fill_template(BIN_TEMPLATE, year, day).as_bytes()) {
+            Ok(_) => {
+                println!("Created binary file \"{}\"", &bin_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to write binary contents: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -107,8 +276,15 @@ fn main() {
     }
 
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if year == advent_of_code::default_year() {
+        println!(
+            "🎄 Type `cargo solve {}` to run your solution.",
+            &day_padded
+        );
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} -- --year {year}` to run your solution.",
+            &day_padded
+        );
+    }
 }
@@ -0,0 +1,143 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{self, Answers};
+use advent_of_code::aoc_client::AocClient;
+use advent_of_code::submit::{self, Attempt, Outcome};
+use advent_of_code::Day;
+use std::{path::Path, process};
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            eprintln!("Need to specify a day and a part. example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    let Some(solution) = Day::find(args.year, args.day) else {
+        eprintln!(
+            "Day {} is not registered in `src/solutions/year{}/mod.rs`.",
+            args.day, args.year
+        );
+        process::exit(1);
+    };
+
+    let solver = match args.part {
+        1 => solution.part_one,
+        2 => solution.part_two,
+        part => {
+            eprintln!("Part {part} does not exist, expected 1 or 2.");
+            process::exit(1);
+        }
+    };
+
+    let input = match advent_of_code::read_file("inputs", args.year, args.day) {
+        Ok(input) => input,
+        Err(e) => {
+            advent_of_code::print_read_error(&e, "inputs", args.year, args.day);
+            process::exit(1);
+        }
+    };
+    let result = solver(&input);
+    if let Some(error) = result.error {
+        eprintln!("Part {} failed, not submitting: {error}", args.part);
+        process::exit(1);
+    }
+    let Some(answer) = result.answer else {
+        eprintln!("Part {} is not solved yet.", args.part);
+        process::exit(1);
+    };
+
+    let attempts_path = submit::get_attempts_path(args.year, args.day);
+    let attempts_path = Path::new(&attempts_path);
+
+    let attempts = match submit::load_attempts(attempts_path) {
+        Ok(attempts) => attempts,
+        Err(e) => {
+            eprintln!("Failed to read attempt log: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = submit::check_attempt(&attempts, args.part, &answer, submit::now()) {
+        eprintln!("Not submitting {answer:?}: {refusal}");
+        process::exit(1);
+    }
+
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to submit: {e}");
+            process::exit(1);
+        }
+    };
+
+    let year = args.year;
+    println!(
+        "Submitting {answer:?} for day {}, part {}, {year}...",
+        args.day, args.part
+    );
+
+    let outcome = match client.submit(args.day, year, args.part, &answer) {
+        Ok(response) => submit::parse_response(&response),
+        Err(e) => {
+            eprintln!("Failed to submit: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) =
+        submit::append_attempt(attempts_path, &Attempt::new(args.part, &answer, outcome))
+    {
+        eprintln!("Failed to write attempt log: {e}");
+    }
+
+    if outcome == Outcome::Correct {
+        let mut answers = Answers::load(args.year, args.day).unwrap_or_default();
+        answers.set(args.part, &answer);
+        match answers.save(args.year, args.day) {
+            Ok(_) => println!(
+                "Saved accepted answer to \"{}\".",
+                answers::get_answers_path(args.year, args.day)
+            ),
+            Err(e) => eprintln!("Failed to save accepted answer: {e}"),
+        }
+    }
+
+    match outcome {
+        Outcome::Correct => println!("⭐ That's the right answer!"),
+        Outcome::TooHigh => println!("❌ That's not the right answer, it is too high."),
+        Outcome::TooLow => println!("❌ That's not the right answer, it is too low."),
+        Outcome::Incorrect => println!("❌ That's not the right answer."),
+        Outcome::Wait(seconds) => {
+            println!("⏳ Answered too recently, wait {seconds}s before retrying.")
+        }
+        Outcome::WrongLevel => println!("🤔 This part is already solved or not unlocked yet."),
+        Outcome::Unknown => println!("🤔 Could not understand the response."),
+    }
+
+    if outcome != Outcome::Correct {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,122 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::watch::{self, Watcher};
+use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{
+    env,
+    process::{self, Command},
+    thread,
+};
+
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// Build the solution and tests with optimizations.
+    release: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        release: args.contains("--release"),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        day: args.free_from_str()?,
+    })
+}
+
+fn cargo(command: &str, args: &Args) -> Command {
+    let mut cmd = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    cmd.args([command, "--quiet"]);
+    if args.release {
+        cmd.arg("--release");
+    }
+    cmd
+}
+
+/// Runs the tests of the day and prints a one-line summary. Returns `false` if the code did not compile.
+fn run_tests(args: &Args) -> bool {
+    let filter = format!("year{}::day{:02}::", args.year, args.day);
+    let output = match cargo("test", args).args(["--lib", "--", &filter]).output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo test: {e}");
+            return false;
+        }
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    match watch::parse_test_summary(&stdout) {
+        Some(summary) if summary.failed == 0 => {
+            println!("✓ {ANSI_BOLD}Tests{ANSI_RESET} {} passed", summary.passed);
+            true
+        }
+        Some(summary) => {
+            println!(
+                "✗ {ANSI_BOLD}Tests{ANSI_RESET} {} failed, {} passed",
+                summary.failed, summary.passed
+            );
+            println!("{stdout}");
+            true
+        }
+        None => {
+            println!("✗ {ANSI_BOLD}Build failed{ANSI_RESET}");
+            println!("{}", String::from_utf8_lossy(&output.stderr));
+            false
+        }
+    }
+}
+
+fn run_solution(args: &Args) {
+    let bin = format!("{:02}", args.day);
+    let status = cargo("run", args)
+        .args(["--bin", &bin, "--", "--year", &args.year.to_string()])
+        .status();
+    if let Err(e) = status {
+        eprintln!("Failed to run cargo run: {e}");
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            eprintln!("Need to specify a day (as integer). example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+    };
+
+    let paths = watch::watched_paths(args.year, args.day);
+    if !paths[0].exists() {
+        eprintln!(
+            "\"{}\" does not exist, run `cargo scaffold {}` first.",
+            paths[0].display(),
+            args.day
+        );
+        process::exit(1);
+    }
+
+    let mut watcher = Watcher::new(paths);
+
+    loop {
+        print!("{ANSI_CLEAR}");
+        println!(
+            "{ANSI_BOLD}Day {:02} ({}){ANSI_RESET} {ANSI_ITALIC}watching for changes, press Ctrl-C to stop.{ANSI_RESET}",
+            args.day, args.year
+        );
+
+        if run_tests(&args) {
+            run_solution(&args);
+        }
+
+        while watcher.changed().is_empty() {
+            thread::sleep(watch::POLL_INTERVAL);
+        }
+    }
+}
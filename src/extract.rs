@@ -0,0 +1,177 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// Heading that starts the second part in the markdown written by `cargo download`.
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// Example input and expected example answers found in a puzzle description.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Example {
+    pub fn from_puzzle(markdown: &str) -> Self {
+        let (part_one, part_two) = match markdown.find(PART_TWO_HEADING) {
+            Some(index) => (&markdown[..index], Some(&markdown[index..])),
+            None => (markdown, None),
+        };
+
+        Self {
+            input: example_input(markdown),
+            part_one: example_answer(part_one),
+            part_two: part_two.and_then(example_answer),
+        }
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut lines = markdown.lines();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("```") {
+            let block: Vec<&str> = lines
+                .by_ref()
+                .take_while(|l| !l.starts_with("```"))
+                .collect();
+            blocks.push(block.join("\n") + "\n");
+        }
+    }
+
+    blocks
+}
+
+/// The first code block spanning multiple lines. Falls back to the longest block
+/// for puzzles where the example input is a single line.
+fn example_input(markdown: &str) -> Option<String> {
+    let blocks = code_blocks(markdown);
+    blocks
+        .iter()
+        .find(|block| block.lines().count() > 1)
+        .or_else(|| blocks.iter().max_by_key(|block| block.len()))
+        .cloned()
+}
+
+/// Text between the `*` markers of emphasized spans outside of code blocks.
+fn emphasized(markdown: &str) -> Vec<&str> {
+    let mut spans = Vec::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        spans.extend(line.split('*').skip(1).step_by(2));
+    }
+
+    spans
+}
+
+/// The example answer of a part is the last emphasized code span of its section,
+/// e.g. "In the example above, this is *`24000`*."
+fn example_answer(section: &str) -> Option<String> {
+    emphasized(section)
+        .into_iter()
+        .rev()
+        .find_map(|span| span.strip_prefix('`')?.strip_suffix('`'))
+        .map(|answer| answer.trim().to_string())
+        .filter(|answer| !answer.is_empty())
+}
+
+/// Replaces the `Ok(None)` assertion of a part in a scaffolded test with the expected answer.
+/// Returns `None` if the assertion was already changed.
+pub fn fill_assertion(module: &str, day: u8, part: u8, answer: &str) -> Option<String> {
+    let function = if part == 1 { "part_one" } else { "part_two" };
+    let placeholder = format!("assert_eq!(Day{day:02}::{function}(&input), Ok(None));");
+
+    if !module.contains(&placeholder) {
+        return None;
+    }
+
+    let expected = if answer.parse::<i64>().is_ok() {
+        format!("Ok(Some({answer}))")
+    } else {
+        format!("Ok(Some({answer:?}.to_string()))")
+    };
+
+    Some(module.replacen(
+        &placeholder,
+        &format!("assert_eq!(Day{day:02}::{function}(&input), {expected});"),
+        1,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "## --- Day 1: Calorie Counting ---
+
+For example:
+
+```
+1000
+2000
+
+3000
+```
+
+The first Elf is carrying *`3000`* Calories.
+
+- In the example above, this is *`24000`* *(the fourth Elf)*.
+
+Find the Elf carrying the most Calories. *How many total Calories is that Elf carrying?*
+
+## --- Part Two ---
+
+The top three Elves are carrying `45000`, in the example that is *`45000`*.
+
+```
+4
+```
+";
+
+    #[test]
+    fn test_from_puzzle() {
+        assert_eq!(
+            Example::from_puzzle(PUZZLE),
+            Example {
+                input: Some("1000\n2000\n\n3000\n".into()),
+                part_one: Some("24000".into()),
+                part_two: Some("45000".into()),
+            }
+        );
+        assert_eq!(Example::from_puzzle("no examples"), Example::default());
+    }
+
+    #[test]
+    fn test_fill_assertion() {
+        let module = "assert_eq!(Day03::part_one(&input), Ok(None));\nassert_eq!(Day03::part_two(&input), Ok(None));";
+        assert_eq!(
+            fill_assertion(module, 3, 2, "CMZ").as_deref(),
+            Some("assert_eq!(Day03::part_one(&input), Ok(None));\nassert_eq!(Day03::part_two(&input), Ok(Some(\"CMZ\".to_string())));")
+        );
+        assert_eq!(
+            fill_assertion(module, 3, 1, "157").as_deref(),
+            Some("assert_eq!(Day03::part_one(&input), Ok(Some(157)));\nassert_eq!(Day03::part_two(&input), Ok(None));")
+        );
+        assert_eq!(fill_assertion("", 3, 1, "157"), None);
+    }
+}
@@ -2,3 +2,11 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+
+pub mod cycle;
+pub mod grid;
+pub mod math;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod search;
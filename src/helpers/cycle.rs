@@ -0,0 +1,198 @@
+/*
+ * Finds where a simulation starts repeating itself, to skip ahead to iteration counts like 10^12.
+ * Example import from this file: `use advent_of_code::helpers::cycle::{brent, find_cycle};`.
+ */
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// A cycle in the states `x0, step(x0), step(step(x0)), ...`: the state after `start` steps
+/// is the first one that comes back, `length` steps later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The iteration before `start + length` whose state is the state after `n` steps.
+    pub fn index(&self, n: u64) -> usize {
+        let start = self.start as u64;
+        if n < start {
+            return n as usize;
+        }
+        self.start + ((n - start) % self.length as u64) as usize
+    }
+
+    /// A metric accumulated over the steps, e.g. the height of a tower, after `n` steps.
+    /// `value(i)` is the metric after `i` steps for `i <= start + length`.
+    /// Every pass through the cycle adds the same amount as the first one.
+    pub fn extrapolate(&self, n: u64, mut value: impl FnMut(usize) -> i64) -> i64 {
+        let start = self.start as u64;
+        if n < start {
+            return value(n as usize);
+        }
+        let (passes, rest) = (
+            (n - start) / self.length as u64,
+            (n - start) % self.length as u64,
+        );
+        let gain = value(self.start + self.length) - value(self.start);
+        value(self.start + rest as usize) + passes as i64 * gain
+    }
+}
+
+/// Brent's algorithm: finds the cycle while keeping only two states in memory.
+/// `step` must be deterministic and is called again for the same states.
+/// The states must eventually repeat, otherwise this never returns.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the tortoise waits at powers of two for the hare to come around.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // with the hare `length` steps ahead, both meet at the start of the cycle.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The states of a simulation up to the end of its first cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History<S> {
+    pub cycle: Cycle,
+    /// The states after 0 to `start + length` steps, so the repeated state is in here twice.
+    pub states: Vec<S>,
+}
+
+impl<S> History<S> {
+    /// The state after `n` steps.
+    pub fn state_at(&self, n: u64) -> &S {
+        &self.states[self.cycle.index(n)]
+    }
+
+    /// A metric of the state after `n` steps, see [`Cycle::extrapolate`].
+    pub fn extrapolate(&self, n: u64, mut metric: impl FnMut(&S) -> i64) -> i64 {
+        self.cycle.extrapolate(n, |i| metric(&self.states[i]))
+    }
+}
+
+/// Finds the cycle by remembering a hash of every state, calling `step` once per state.
+/// The states must eventually repeat, otherwise this never returns.
+pub fn find_cycle<S: Clone + Hash + Eq>(initial: S, step: impl FnMut(&S) -> S) -> History<S> {
+    find_cycle_by_key(initial, step, S::clone)
+}
+
+/// Like [`find_cycle`], but compares the `key` of the states. Useful when the state holds
+/// values that keep growing, e.g. a score, while the key is the part that determines what comes next.
+pub fn find_cycle_by_key<S, K: Hash + Eq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> History<S> {
+    let mut seen = HashMap::from([(key(&initial), 0)]);
+    let mut states = vec![initial];
+
+    loop {
+        let next = step(states.last().unwrap());
+        let index = states.len();
+        let entry = seen.entry(key(&next));
+        states.push(next);
+        if let Entry::Occupied(entry) = entry {
+            let start = *entry.get();
+            return History {
+                cycle: Cycle {
+                    start,
+                    length: index - start,
+                },
+                states,
+            };
+        }
+        entry.or_insert(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(x: &u64) -> u64 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn test_brent_matches_find_cycle() {
+        for initial in 0..255 {
+            let history = find_cycle(initial, step);
+            assert_eq!(brent(initial, step), history.cycle);
+
+            let Cycle { start, length } = history.cycle;
+            assert_eq!(history.states.len(), start + length + 1);
+            assert_eq!(history.states[start], history.states[start + length]);
+        }
+
+        // 3 -> 10 -> 101 -> 2 -> 5 -> 26 -> 167 -> 95 -> 101
+        assert_eq!(
+            brent(3, step),
+            Cycle {
+                start: 2,
+                length: 6
+            }
+        );
+        assert_eq!(
+            brent(0, |_| 0),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_state_at() {
+        let history = find_cycle(3, step);
+        let mut state = 3;
+        for n in 0..100 {
+            assert_eq!(*history.state_at(n), state);
+            state = step(&state);
+        }
+        assert_eq!(*history.state_at(1_000_000_000_000), 5);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // the sum of all states seen so far keeps growing, the last state is the key.
+        let history =
+            find_cycle_by_key((3, 3), |&(x, sum)| (step(&x), sum + step(&x)), |&(x, _)| x);
+        let sum = |n| history.extrapolate(n, |&(_, sum)| sum as i64);
+
+        let mut expected = (3, 3);
+        for n in 0..100 {
+            assert_eq!(sum(n), expected.1 as i64);
+            expected = (step(&expected.0), expected.1 + step(&expected.0));
+        }
+
+        // 3 + 10 before the cycle, then 396 for every pass through 101, 2, 5, 26, 167 and 95.
+        assert_eq!(sum(1_000_000_000_000), 65_999_999_999_857);
+    }
+}
@@ -0,0 +1,329 @@
+/*
+ * A rectangle of cells, e.g. the character matrices most puzzles use as input.
+ * Example import from this file: `use advent_of_code::helpers::grid::Grid;`.
+ */
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+/// Position of a cell as `(x, y)`, where `x` is the column and `y` the row counted from the top.
+pub type Position = (usize, usize);
+
+/// Offsets `(dx, dy)` to the cells above, right, below and left of a cell.
+pub const NEIGHBORS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets `(dx, dy)` to the eight cells around a cell, clockwise starting above it.
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Why [`Grid::parse`] could not read a grid. Lines and columns count from 1.
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// The input has no lines.
+    Empty,
+    /// A line is shorter or longer than the first line.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The cell parser rejected a character.
+    InvalidChar { line: usize, column: usize, c: char },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "the grid has no rows"),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} cells, expected {expected} like the first line"
+            ),
+            GridError::InvalidChar { line, column, c } => {
+                write!(f, "invalid cell {c:?} at line {line}, column {column}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one row per line of `input`, turning every character into a cell with `cell`.
+    /// Trailing whitespace of lines and empty lines at the end of the input are ignored.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let lines: Vec<&str> = input.trim_end().lines().map(str::trim_end).collect();
+        let width = match lines.first() {
+            Some(line) if !input.trim().is_empty() => line.chars().count(),
+            _ => return Err(GridError::Empty),
+        };
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let found = line.chars().count();
+            if found != width {
+                return Err(GridError::Ragged {
+                    line: y + 1,
+                    expected: width,
+                    found,
+                });
+            }
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or(GridError::InvalidChar {
+                    line: y + 1,
+                    column: x + 1,
+                    c,
+                })?;
+                cells.push(value);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    /// Creates a grid with the cell at every position set to `cell(position)`.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(x, y)`, `None` if it is outside of the grid.
+    pub fn get(&self, (x, y): Position) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Position) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// The position `(dx, dy)` away from `position`, `None` if it is outside of the grid.
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Positions of the cells above, right, below and left of `position` that are inside the grid.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBORS4
+            .into_iter()
+            .filter_map(move |delta| self.offset(position, delta))
+    }
+
+    /// Positions of the up to eight cells around `position`, including diagonals.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBORS8
+            .into_iter()
+            .filter_map(move |delta| self.offset(position, delta))
+    }
+
+    /// Cells in a line from `position` in steps of `delta`, excluding `position` itself,
+    /// until the edge of the grid. E.g. `grid.ray(position, (0, -1))` looks up.
+    pub fn ray(
+        &self,
+        position: Position,
+        delta: (isize, isize),
+    ) -> impl Iterator<Item = (Position, &T)> + '_ {
+        let mut current = Some(position);
+        std::iter::from_fn(move || {
+            current = self.offset(current?, delta);
+            current.map(|position| (position, &self[position]))
+        })
+    }
+
+    /// Converts every cell with `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Turns the grid by 90° so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Turns the grid by 90° so the right column becomes the top row.
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(position).unwrap_or_else(|| {
+            panic!("position {position:?} is outside of the {width}x{height} grid")
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!("position {position:?} is outside of the {width}x{height} grid")
+        })
+    }
+}
+
+/// Prints one line per row with the cells next to each other, e.g. the `#`/`.` picture of a `Grid<char>`.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert_eq!(
+            Grid::parse("12\n345", |c| c.to_digit(10)),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            Grid::parse("12\n3x", |c| c.to_digit(10)),
+            Err(GridError::InvalidChar {
+                line: 2,
+                column: 2,
+                c: 'x'
+            })
+        );
+        assert_eq!(Grid::parse("\n", Some), Err(GridError::Empty));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = digits("123\n456\n789");
+        let values = |delta| {
+            grid.ray((1, 2), delta)
+                .map(|(_, value)| *value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values((0, -1)), vec![5, 2]);
+        assert_eq!(values((1, -1)), vec![6]);
+        assert_eq!(values((0, 1)), vec![]);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+}
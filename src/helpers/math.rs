@@ -0,0 +1,148 @@
+/*
+ * Number theory for puzzles that work with remainders, e.g. simulations that only need values modulo some divisors.
+ * Example import from this file: `use advent_of_code::helpers::math::{gcd, lcm};`.
+ */
+
+/// Greatest common divisor. `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `None` if it does not fit into a `u64`. `lcm(0, n)` is 0.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all `values`, 1 if there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// `a * b % modulus` without overflowing, even if `a * b` does not fit into a `u64`. Panics if `modulus` is 0.
+pub fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base.pow(exponent) % modulus` by repeated squaring. Panics if `modulus` is 0.
+pub fn pow_mod(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Extended Euclidean algorithm: `(g, x, y)` with `a * x + b * y = g = gcd(a, b)`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1_i128, 0_i128);
+    let (mut old_y, mut y) = (0_i128, 1_i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    (old_r as i64, old_x as i64, old_y as i64)
+}
+
+/// The `x` in `0..modulus` with `a * x % modulus == 1`, `None` if `a` and `modulus` share a divisor
+/// or `modulus` is not positive.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Chinese remainder theorem: the smallest `x >= 0` with `x % modulus == remainder` for all
+/// `(remainder, modulus)` pairs, returned with the lcm of the moduli as `(x, lcm)`.
+/// The moduli don't need to be coprime. `None` if the congruences contradict each other,
+/// a modulus is not positive or the lcm does not fit into an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for &(remainder, m) in congruences {
+        if m <= 0 {
+            return None;
+        }
+        let m = m as i128;
+        let (g, p, _) = extended_gcd(modulus as i64, m as i64);
+        let g = g as i128;
+        let difference = remainder as i128 - x;
+        if difference % g != 0 {
+            return None;
+        }
+
+        // x + modulus * k solves the new congruence for k = difference / g * p (mod m / g).
+        let step = m / g;
+        let k = (difference / g % step * p as i128).rem_euclid(step);
+        x += modulus * k;
+        modulus *= step;
+        if modulus > i64::MAX as i128 {
+            return None;
+        }
+        x = x.rem_euclid(modulus);
+    }
+
+    Some((x as i64, modulus as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(lcm_all([23, 19, 13, 17]), Some(96_577));
+        assert_eq!(lcm_all([2, 4, 6]), Some(12));
+        assert_eq!(lcm_all([]), Some(1));
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        assert_eq!(mul_mod(u64::MAX, u64::MAX, 1_000_000_007), 114_944_269);
+        assert_eq!(pow_mod(2, 10, 1000), 24);
+        assert_eq!(pow_mod(3, 0, 1), 0);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(3, 0), None);
+
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // not coprime, but consistent.
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(2, 3), (0, 0)]), None);
+        // 2020 day 13: 17,x,13,19 departs at 3417.
+        assert_eq!(crt(&[(0, 17), (-2, 13), (-3, 19)]), Some((3417, 4199)));
+    }
+}
@@ -0,0 +1,226 @@
+/*
+ * Reads the block letters that some puzzles draw instead of printing an answer.
+ * Example import from this file: `use advent_of_code::helpers::ocr;`.
+ */
+use std::fmt::Display;
+
+/// Letters of the 4x6 font, e.g. the CRT of 2022 day 10. Letters are separated by one dark column.
+const SMALL_FONT: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters of the 6x10 font, e.g. the message of 2018 day 10. Letters are separated by two dark columns.
+const LARGE_FONT: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Why a picture could not be read by [`parse_letters`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// The lit rows of the picture are neither 6 nor 10 rows high.
+    UnknownHeight(usize),
+    /// The letter starting at this column of the picture is not part of the font.
+    UnknownLetter { column: usize, glyph: String },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::UnknownHeight(height) => {
+                write!(f, "letters are {height} rows high, expected 6 or 10")
+            }
+            OcrError::UnknownLetter { column, glyph } => {
+                write!(f, "unknown letter at column {column}:\n{glyph}")
+            }
+        }
+    }
+}
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+/// Reads the block letters drawn in `picture`, one line per row.
+/// `#` and `█` are lit pixels, every other character is dark.
+/// Letters are told apart by the dark columns between them, so the picture may have any margin.
+pub fn parse_letters(picture: &str) -> Result<String, OcrError> {
+    let rows: Vec<Vec<bool>> = picture
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect())
+        .skip_while(|row: &Vec<bool>| !row.contains(&true))
+        .collect();
+    let height = rows
+        .iter()
+        .rposition(|row| row.contains(&true))
+        .map_or(0, |last| last + 1);
+    let rows = &rows[..height];
+
+    let font = match height {
+        6 => SMALL_FONT,
+        10 => LARGE_FONT,
+        _ => return Err(OcrError::UnknownHeight(height)),
+    };
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let column_lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if !column_lit(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && column_lit(x) {
+            x += 1;
+        }
+
+        let glyph = rows
+            .iter()
+            .map(|row| {
+                (start..x)
+                    .map(|x| match row.get(x) {
+                        Some(true) => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match font.iter().find(|(_, pattern)| *pattern == glyph) {
+            Some((letter, _)) => letters.push(*letter),
+            None => {
+                return Err(OcrError::UnknownLetter {
+                    column: start,
+                    glyph,
+                })
+            }
+        }
+    }
+
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_small_letters() {
+        let picture = "
+###..###....##.#....####.#..#.#....###..
+#..#.#..#....#.#....#....#..#.#....#..#.
+###..#..#....#.#....###..#..#.#....#..#.
+#..#.###.....#.#....#....#..#.#....###..
+#..#.#.#..#..#.#....#....#..#.#....#....
+###..#..#..##..####.#.....##..####.#....";
+        assert_eq!(parse_letters(picture), Ok("BRJLFULP".into()));
+    }
+
+    #[test]
+    fn test_parse_large_letters() {
+        let picture = "
+#....#..######
+#....#..#.....
+#....#..#.....
+#....#..#.....
+######..#####.
+#....#..#.....
+#....#..#.....
+#....#..#.....
+#....#..#.....
+#....#..######
+";
+        assert_eq!(parse_letters(picture), Ok("HE".into()));
+    }
+
+    #[test]
+    fn test_parse_letters_errors() {
+        assert_eq!(parse_letters("#\n#"), Err(OcrError::UnknownHeight(2)));
+        assert_eq!(
+            parse_letters(".##\n#..\n#..\n#..\n#..\n.##"),
+            Err(OcrError::UnknownLetter {
+                column: 0,
+                glyph: ".##\n#..\n#..\n#..\n#..\n.##".into()
+            })
+        );
+    }
+}
@@ -0,0 +1,287 @@
+/*
+ * Pulls numbers and fields out of puzzle input lines, with errors instead of panics.
+ * Example import from this file: `use advent_of_code::helpers::parse::{ints, paragraphs};`.
+ */
+use std::{any::type_name, fmt::Display, str::FromStr};
+
+/// A piece of a line that could not be read. Columns count characters from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenError {
+    pub column: usize,
+    /// The text that was found, empty at the end of the line.
+    pub text: String,
+    /// What was expected instead, e.g. a type name or the literal text of a pattern.
+    pub expected: String,
+}
+
+impl Display for TokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.text.is_empty() {
+            write!(
+                f,
+                "expected {} at column {}, found the end of the line",
+                self.expected, self.column
+            )
+        } else {
+            write!(
+                f,
+                "expected {} at column {}, found {:?}",
+                self.expected, self.column, self.text
+            )
+        }
+    }
+}
+
+impl TokenError {
+    /// An error about `token`, which must be a slice of `line`.
+    pub fn at(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        Self {
+            column: crate::offset_in(line, token).map_or(0, |offset| column(line, offset)),
+            text: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+}
+
+/// The column of the byte `offset` in `line`.
+fn column(line: &str, offset: usize) -> usize {
+    line[..offset].chars().count() + 1
+}
+
+fn parse_token<T: FromStr>(line: &str, offset: usize, token: &str) -> Result<T, TokenError> {
+    token.parse().map_err(|_| TokenError {
+        column: column(line, offset),
+        text: token.to_string(),
+        expected: type_name::<T>().to_string(),
+    })
+}
+
+/// All integers in `line`, in order. A `-` directly before digits is a sign unless it follows a digit,
+/// so `x=-3` reads -3 and the range `2-4` reads 2 and 4.
+/// Fails if a number does not fit into `T`, e.g. a negative number for an unsigned type.
+pub fn ints<T: FromStr>(line: &str) -> Result<Vec<T>, TokenError> {
+    let bytes = line.as_bytes();
+    let mut values = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        values.push(parse_token(line, start, &line[start..i])?);
+    }
+
+    Ok(values)
+}
+
+/// Splits `input` at blank lines, e.g. into the stacks and the moves of 2022 day 5.
+/// Works with `\n` and `\r\n` line endings. Paragraphs keep the indentation of their first line
+/// but not the line break after their last line.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+    std::iter::from_fn(move || {
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+
+        for line in rest.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.trim().is_empty() {
+                if start.is_some() {
+                    break;
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + content.len();
+            }
+            offset += line.len();
+        }
+
+        let paragraph = &rest[start?..end];
+        rest = &rest[offset..];
+        Some(paragraph)
+    })
+}
+
+/// Values captured by [`scan`], converted into the tuple they are assigned to.
+pub trait FromCaptures: Sized {
+    /// `captures` are the texts of the placeholders of `line` with their byte offsets.
+    fn from_captures(line: &str, captures: &[(usize, &str)]) -> Result<Self, TokenError>;
+}
+
+macro_rules! impl_from_captures {
+    ($count:literal: $($value:ident),+) => {
+        impl<$($value: FromStr),+> FromCaptures for ($($value,)+) {
+            fn from_captures(line: &str, captures: &[(usize, &str)]) -> Result<Self, TokenError> {
+                if captures.len() != $count {
+                    return Err(TokenError {
+                        column: 1,
+                        text: line.to_string(),
+                        expected: format!("a pattern with {} placeholders", $count),
+                    });
+                }
+                let mut captures = captures.iter();
+                Ok(($({
+                    let (offset, text) = captures.next().unwrap();
+                    parse_token::<$value>(line, *offset, text)?
+                },)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(1: A);
+impl_from_captures!(2: A, B);
+impl_from_captures!(3: A, B, C);
+impl_from_captures!(4: A, B, C, D);
+impl_from_captures!(5: A, B, C, D, E);
+impl_from_captures!(6: A, B, C, D, E, F);
+impl_from_captures!(7: A, B, C, D, E, F, G);
+impl_from_captures!(8: A, B, C, D, E, F, G, H);
+
+/// Matches `line` against `pattern`, where every `{}` captures a value up to the text that follows it.
+/// Surrounding whitespace of the line, the pattern and the values is ignored.
+/// Use it through [`scan!`](crate::scan).
+pub fn scan<T: FromCaptures>(pattern: &str, line: &str) -> Result<T, TokenError> {
+    let trimmed = line.trim();
+    // offsets are reported relative to the untrimmed line.
+    let base = line.len() - line.trim_start().len();
+    let literals: Vec<&str> = pattern.trim().split("{}").collect();
+
+    let mismatch = |offset: usize, expected: &str| {
+        let found = &trimmed[offset..];
+        TokenError {
+            column: column(line, base + offset),
+            text: found
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            expected: format!("{expected:?}"),
+        }
+    };
+
+    let Some(rest) = trimmed.strip_prefix(literals[0]) else {
+        return Err(mismatch(0, literals[0]));
+    };
+    let mut offset = trimmed.len() - rest.len();
+    let mut captures = Vec::with_capacity(literals.len() - 1);
+
+    for (i, literal) in literals.iter().enumerate().skip(1) {
+        let rest = &trimmed[offset..];
+        let is_last = i == literals.len() - 1;
+        let length = match (literal.is_empty(), is_last) {
+            (true, true) => rest.len(),
+            (true, false) => {
+                return Err(TokenError {
+                    column: 1,
+                    text: pattern.to_string(),
+                    expected: "text between two `{}` of the pattern".into(),
+                })
+            }
+            // the last value runs until the final literal at the end of the line.
+            (false, true) if rest.ends_with(literal) => rest.len() - literal.len(),
+            (false, true) => return Err(mismatch(trimmed.len(), literal)),
+            (false, false) => match rest.find(literal) {
+                Some(length) => length,
+                None => return Err(mismatch(trimmed.len(), literal)),
+            },
+        };
+
+        let value = &rest[..length];
+        let leading = value.len() - value.trim_start().len();
+        captures.push((base + offset + leading, value.trim()));
+        offset += length + literal.len();
+    }
+
+    T::from_captures(line, &captures)
+}
+
+/// Reads the values of `line` in the places of the `{}` of `pattern` into a tuple, or returns a [`TokenError`].
+/// E.g. `let (amount, from, to): (usize, usize, usize) = scan!("move {} from {} to {}", line)?;`
+#[macro_export]
+macro_rules! scan {
+    ($pattern:expr, $line:expr) => {
+        $crate::helpers::parse::scan($pattern, $line)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i32>("x=-3, y=14"), Ok(vec![-3, 14]));
+        assert_eq!(ints::<u32>("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(ints::<u32>("no numbers"), Ok(vec![]));
+        assert_eq!(
+            ints::<u32>("a: 1, b: -2"),
+            Err(TokenError {
+                column: 10,
+                text: "-2".into(),
+                expected: "u32".into()
+            })
+        );
+        assert!(ints::<u8>("300").is_err());
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let input = "a\nb\n\n\nc\n";
+        assert_eq!(paragraphs(input).collect::<Vec<_>>(), vec!["a\nb", "c"]);
+
+        let input = "\r\n  a\r\nb\r\n\r\nc";
+        assert_eq!(paragraphs(input).collect::<Vec<_>>(), vec!["  a\r\nb", "c"]);
+        assert_eq!(paragraphs("\n \n").count(), 0);
+    }
+
+    #[test]
+    fn test_scan() {
+        let (amount, from, to): (usize, usize, usize) =
+            scan!("move {} from {} to {}", "move 13 from 1 to 2").unwrap();
+        assert_eq!((amount, from, to), (13, 1, 2));
+
+        let (number,): (u8,) = scan!("Monkey {}:", "Monkey 7:").unwrap();
+        assert_eq!(number, 7);
+
+        let (name, items): (String, String) = scan!("{}: {}", "  Starting items: 79, 98").unwrap();
+        assert_eq!(
+            (name.as_str(), items.as_str()),
+            ("Starting items", "79, 98")
+        );
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let error = scan::<(u32, u32)>("move {} to {}", "move x to 2").unwrap_err();
+        assert_eq!(
+            error,
+            TokenError {
+                column: 6,
+                text: "x".into(),
+                expected: "u32".into()
+            }
+        );
+
+        let error = scan::<(u32,)>("turn {} on", "toggle 5 on").unwrap_err();
+        assert_eq!(error.column, 1);
+        assert_eq!(error.expected, "\"turn \"");
+
+        let error = scan::<(u32,)>("{} on", "5 off").unwrap_err();
+        assert_eq!(error.expected, "\" on\"");
+
+        assert!(scan::<(u32, u32)>("{} and {}", "1 and 2 and 3").is_err());
+        assert!(scan::<(u32,)>("{} and {}", "1 and 2").is_err());
+    }
+}
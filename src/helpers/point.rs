@@ -0,0 +1,210 @@
+/*
+ * Points, vectors and directions on an integer plane.
+ * Example import from this file: `use advent_of_code::helpers::point::{Direction, Point};`.
+ */
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use super::grid::Position;
+
+/// A point, or the vector between two points. `y` grows downwards like the rows of a
+/// [`Grid`](super::grid::Grid), so [`Direction::Up`] decreases it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// [`Point`] when it is used as the difference between two points.
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The vector with each component replaced by its sign, i.e. one step towards it,
+    /// diagonally if neither component is zero.
+    pub fn signum(self) -> Vec2 {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Distance to `other` moving only horizontally and vertically.
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance to `other` when diagonal moves are allowed, like a king in chess.
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The four points next to this one, in the order of [`Direction::ALL`].
+    pub fn neighbors4(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self + direction.delta())
+    }
+
+    /// The grid position of this point, `None` if a component is negative.
+    pub fn to_position(self) -> Option<Position> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<Position> for Point {
+    fn from((x, y): Position) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, other: Point) -> Vec2 {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// One of the four directions on the plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting with [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Reads `U`/`R`/`D`/`L`, `N`/`E`/`S`/`W` or `^`/`>`/`v`/`<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' => Some(Direction::Up),
+            'R' | 'E' | '>' => Some(Direction::Right),
+            'D' | 'S' | 'v' => Some(Direction::Down),
+            'L' | 'W' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The vector of one step in this direction.
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// The step as `(dx, dy)`, as taken by [`Grid::offset`](super::grid::Grid::offset) and
+    /// [`Grid::ray`](super::grid::Grid::ray).
+    pub fn offset(self) -> (isize, isize) {
+        let delta = self.delta();
+        (delta.x as isize, delta.y as isize)
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(-a * 2, Point::new(-6, 4));
+        assert_eq!((b - a).signum(), Point::new(-1, 1));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+    }
+
+    #[test]
+    fn test_position() {
+        assert_eq!(Point::new(2, 3).to_position(), Some((2, 3)));
+        assert_eq!(Point::new(-1, 3).to_position(), None);
+        assert_eq!(Point::from((4, 1)), Point::new(4, 1));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('x'), None);
+
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.delta() + direction.opposite().delta(),
+                Point::ORIGIN
+            );
+        }
+    }
+}
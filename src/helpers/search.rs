@@ -0,0 +1,258 @@
+/*
+ * Shortest paths through states that are generated on the fly: grid positions, or any hashable state.
+ * Example import from this file: `use advent_of_code::helpers::search::{bfs, dijkstra, astar};`.
+ */
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A shortest path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// States from the start to the goal, both included.
+    pub states: Vec<S>,
+    /// Sum of the step costs, the number of steps for [`bfs`].
+    pub cost: C,
+    /// Number of states taken from the queue before the goal was reached.
+    pub visited: usize,
+}
+
+/// Follows the parents stored with `nodes` back from `goal` to the start.
+fn reconstruct<S: Clone>(nodes: &[(S, Option<usize>)], goal: usize) -> Vec<S> {
+    let mut states = Vec::new();
+    let mut current = Some(goal);
+    while let Some(index) = current {
+        let (state, parent) = &nodes[index];
+        states.push(state.clone());
+        current = *parent;
+    }
+    states.reverse();
+    states
+}
+
+/// Breadth-first search for the path with the fewest steps from `start` to a state where `is_goal` holds.
+/// `neighbors` lists the states one step away from a state.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    // every state ever queued with the index of the state it was reached from.
+    let mut nodes = vec![(start.clone(), None)];
+    let mut steps = vec![0];
+    let mut seen = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([0]);
+    let mut visited = 0;
+
+    while let Some(index) = queue.pop_front() {
+        visited += 1;
+        if is_goal(&nodes[index].0) {
+            return Some(Path {
+                states: reconstruct(&nodes, index),
+                cost: steps[index],
+                visited,
+            });
+        }
+
+        for next in neighbors(&nodes[index].0) {
+            if let Entry::Vacant(entry) = seen.entry(next.clone()) {
+                entry.insert(nodes.len());
+                queue.push_back(nodes.len());
+                steps.push(steps[index] + 1);
+                nodes.push((next, Some(index)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm: the cheapest path from `start` to a state where `is_goal` holds.
+/// `neighbors` lists the states one step away from a state with the cost of that step.
+/// `C::default()` must be zero and costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search: like [`dijkstra`], but states are tried in the order of their cost so far plus `heuristic`.
+/// The heuristic must never overestimate the remaining cost to the goal, e.g. the Manhattan distance
+/// on a grid where every step costs at least 1. Visits fewer states than [`dijkstra`] the closer it is.
+/// If the heuristic is not consistent, i.e. `h(a) > cost(a, b) + h(b)` for some step, states may be visited again.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut nodes = vec![(start.clone(), None)];
+    // cheapest known cost to each node, and whether its neighbors were queued with that cost.
+    let mut costs = vec![C::default()];
+    let mut done = vec![false];
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut visited = 0;
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if done[index] || cost > costs[index] {
+            continue;
+        }
+        done[index] = true;
+        visited += 1;
+
+        if is_goal(&nodes[index].0) {
+            return Some(Path {
+                states: reconstruct(&nodes, index),
+                cost,
+                visited,
+            });
+        }
+
+        for (next, step) in neighbors(&nodes[index].0) {
+            let next_cost = cost + step;
+            let next_index = match seen.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= costs[next_index] {
+                        continue;
+                    }
+                    // a heuristic that is not consistent can finish a node before its cheapest route is found.
+                    done[next_index] = false;
+                    costs[next_index] = next_cost;
+                    nodes[next_index].1 = Some(index);
+                    next_index
+                }
+                Entry::Vacant(entry) => {
+                    let next_index = nodes.len();
+                    nodes.push((entry.key().clone(), Some(index)));
+                    entry.insert(next_index);
+                    costs.push(next_cost);
+                    done.push(false);
+                    next_index
+                }
+            };
+            let estimate = next_cost + heuristic(&nodes[next_index].0);
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::grid::Grid;
+    use crate::helpers::point::Point;
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    #[test]
+    fn test_bfs_grid() {
+        let grid = Grid::parse(MAZE, Some).unwrap();
+        let path = bfs(
+            (0, 0),
+            |&position| grid.neighbors4(position).filter(|&next| grid[next] != '#'),
+            |&position| grid[position] == 'E',
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.states.len(), 16);
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(path.states.last(), Some(&(7, 4)));
+        assert!(path.visited >= path.states.len());
+
+        assert_eq!(bfs((0, 0), |_| Vec::new(), |&p| p == (1, 1)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // a -> c directly costs 10, through b only 3.
+        let edges: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+            ('a', vec![('b', 1), ('c', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 1)]),
+        ]);
+        let path = dijkstra(
+            'a',
+            |state| edges.get(state).cloned().unwrap_or_default(),
+            |&state| state == 'd',
+        )
+        .unwrap();
+
+        assert_eq!(path.states, vec!['a', 'b', 'c', 'd']);
+        assert_eq!(path.cost, 4);
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let grid = Grid::parse(MAZE, Some).unwrap();
+        let goal = Point::new(7, 4);
+        let neighbors = |&position: &(usize, usize)| {
+            grid.neighbors4(position)
+                .filter(|&next| grid[next] != '#')
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let is_goal = |&position: &(usize, usize)| grid[position] == 'E';
+
+        let shortest = dijkstra((0, 0), neighbors, is_goal).unwrap();
+        let guided = astar(
+            (0, 0),
+            neighbors,
+            |&position| Point::from(position).manhattan(goal),
+            is_goal,
+        )
+        .unwrap();
+
+        assert_eq!(guided.cost, shortest.cost);
+        assert_eq!(guided.cost, 15);
+        assert!(guided.visited <= shortest.visited);
+    }
+
+    #[test]
+    fn test_astar_inconsistent_heuristic() {
+        // h(A) = 4 never overestimates, but makes B look done through the expensive edge first.
+        let edges: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+            ('S', vec![('A', 1), ('B', 3)]),
+            ('A', vec![('B', 1)]),
+            ('B', vec![('G', 3)]),
+        ]);
+        let path = astar(
+            'S',
+            |state| edges.get(state).cloned().unwrap_or_default(),
+            |&state| if state == 'A' { 4 } else { 0 },
+            |&state| state == 'G',
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states, vec!['S', 'A', 'B', 'G']);
+    }
+}
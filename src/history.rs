@@ -0,0 +1,215 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Each year keeps its own history, so days of different years never get compared.
+pub fn get_history_path(year: u16) -> String {
+    format!(".benchmarks/{year}.csv")
+}
+
+pub const HISTORY_HEADER: &str = "timestamp,profile,day,part,elapsed_ns";
+
+/// Default slowdown (in percent) that `--compare` reports as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Timing of one part in one run of `cargo all`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub profile: String,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed_ns: u128,
+}
+
+impl Entry {
+    pub fn new(day: u8, part: u8, elapsed_ns: u128) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            profile: current_profile().into(),
+            day,
+            part,
+            elapsed_ns,
+        }
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split(',');
+        let entry = Self {
+            timestamp: fields.next()?.parse().ok()?,
+            profile: fields.next()?.to_string(),
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            elapsed_ns: fields.next()?.parse().ok()?,
+        };
+        fields.next().is_none().then_some(entry)
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            self.timestamp, self.profile, self.day, self.part, self.elapsed_ns
+        )
+    }
+}
+
+/// Timings from debug and release builds are never compared with each other.
+pub fn current_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// Reads all entries from the history file. A missing file is an empty history.
+pub fn load(path: &Path) -> io::Result<Vec<Entry>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents.lines().filter_map(Entry::parse).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn append(path: &Path, entries: &[Entry]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if is_new {
+        writeln!(file, "{HISTORY_HEADER}")?;
+    }
+
+    for entry in entries {
+        writeln!(file, "{}", entry.to_csv())?;
+    }
+
+    Ok(())
+}
+
+/// The most recent timing of every `(day, part)` recorded with `profile`.
+pub fn baseline(entries: &[Entry], profile: &str) -> HashMap<(u8, u8), u128> {
+    let mut baseline = HashMap::new();
+    for entry in entries.iter().filter(|e| e.profile == profile) {
+        // later lines were appended by later runs.
+        baseline.insert((entry.day, entry.part), entry.elapsed_ns);
+    }
+    baseline
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub part: u8,
+    pub baseline_ns: u128,
+    pub current_ns: u128,
+    /// Slowdown relative to the baseline, in percent.
+    pub change: f64,
+}
+
+/// Parts of `current` that are more than `threshold` percent slower than in `baseline`.
+pub fn find_regressions(
+    baseline: &HashMap<(u8, u8), u128>,
+    current: &[Entry],
+    threshold: f64,
+) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|entry| {
+            let baseline_ns = *baseline.get(&(entry.day, entry.part))?;
+            if baseline_ns == 0 {
+                return None;
+            }
+
+            let change =
+                (entry.elapsed_ns as f64 - baseline_ns as f64) / baseline_ns as f64 * 100_f64;
+
+            (change > threshold).then_some(Regression {
+                day: entry.day,
+                part: entry.part,
+                baseline_ns,
+                current_ns: entry.elapsed_ns,
+                change,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(profile: &str, day: u8, part: u8, elapsed_ns: u128) -> Entry {
+        Entry {
+            timestamp: 0,
+            profile: profile.into(),
+            day,
+            part,
+            elapsed_ns,
+        }
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        assert_eq!(
+            Entry::parse("1670000000,release,9,2,1500"),
+            Some(Entry {
+                timestamp: 1670000000,
+                profile: "release".into(),
+                day: 9,
+                part: 2,
+                elapsed_ns: 1500
+            })
+        );
+        assert_eq!(Entry::parse(HISTORY_HEADER), None);
+        assert_eq!(Entry::parse("1670000000,release,9,2,1500,1"), None);
+    }
+
+    #[test]
+    fn test_baseline_uses_latest_entry_of_profile() {
+        let entries = [
+            entry("release", 1, 1, 100),
+            entry("release", 1, 1, 200),
+            entry("debug", 1, 1, 5000),
+            entry("release", 1, 2, 300),
+        ];
+        let baseline = baseline(&entries, "release");
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline[&(1, 1)], 200);
+        assert_eq!(baseline[&(1, 2)], 300);
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let baseline = HashMap::from([((1, 1), 100), ((1, 2), 100)]);
+        let current = [
+            entry("release", 1, 1, 105),
+            entry("release", 1, 2, 150),
+            entry("release", 2, 1, 1000),
+        ];
+        let regressions = find_regressions(&baseline, &current, 10.0);
+        assert_eq!(
+            regressions,
+            vec![Regression {
+                day: 1,
+                part: 2,
+                baseline_ns: 100,
+                current_ns: 150,
+                change: 50.0
+            }]
+        );
+    }
+}
@@ -3,92 +3,736 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use std::any::Any;
 use std::env;
+use std::fmt::Display;
 use std::fs;
-
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use alloc_stats::AllocStats;
+use answers::Answers;
+use report::{Format, Record, CSV_HEADER};
+
+pub mod alloc_stats;
+pub mod answers;
+pub mod aoc_client;
+pub mod bench;
+pub mod extract;
 pub mod helpers;
+pub mod history;
+pub mod markdown;
+pub mod report;
+pub mod solutions;
+pub mod submit;
+pub mod watch;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: alloc_stats::CountingAllocator = alloc_stats::CountingAllocator;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
+/// Environment variable holding the default year of all commands, set in `.cargo/config`.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// The year used when no `--year` is passed: `AOC_YEAR` if set, the latest event otherwise.
+pub fn default_year() -> u16 {
+    env::var(YEAR_ENV)
+        .ok()
+        .and_then(|year| year.parse().ok())
+        .unwrap_or_else(aoc_client::current_event_year)
+}
+
+/// Set to any value to let solutions print what they would otherwise only return, e.g. `AOC_DEBUG=1 cargo solve 10`.
+pub const DEBUG_ENV: &str = "AOC_DEBUG";
+
+/// `true` if `AOC_DEBUG` is set.
+pub fn debug_enabled() -> bool {
+    env::var_os(DEBUG_ENV).is_some()
+}
+
+/// Set by `cargo all` to a file that a day's executable appends a CSV row to for every part it ran.
+pub const RECORDS_ENV: &str = "AOC_RECORDS";
+
+/// A solved puzzle day. Implementors are listed in `src/solutions/yearYYYY/mod.rs`.
+pub trait Solution {
+    const DAY: u8;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn part_one(input: &str) -> Answer<Self::PartOne>;
+    fn part_two(input: &str) -> Answer<Self::PartTwo>;
+}
+
+/// What a part returns: `Ok(None)` while it is not solved, `Err` if the input could not be read.
+pub type Answer<T> = Result<Option<T>, ParseError>;
+
+/// Return values accepted by [`run_part`] and `solve!`: an [`Answer`], or an `Option` for parts that can't fail.
+pub trait IntoAnswer {
+    type Value: Display;
+
+    fn into_answer(self) -> Answer<Self::Value>;
+}
+
+impl<T: Display> IntoAnswer for Option<T> {
+    type Value = T;
+
+    fn into_answer(self) -> Answer<T> {
+        Ok(self)
+    }
+}
+
+impl<T: Display> IntoAnswer for Answer<T> {
+    type Value = T;
+
+    fn into_answer(self) -> Answer<T> {
+        self
+    }
+}
+
+/// Why a solution could not read its input. Lines and columns count from 1, they are 0 if unknown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, empty if the input ended early.
+    pub text: String,
+    /// What was expected instead, e.g. a type name or the literal text of a pattern.
+    pub expected: String,
+    /// The whole line of the input containing the error.
+    pub source_line: String,
+}
+
+/// Byte offset of `part` in `whole`, `None` if `part` is not a slice of `whole`.
+pub(crate) fn offset_in(whole: &str, part: &str) -> Option<usize> {
+    let offset = (part.as_ptr() as usize).checked_sub(whole.as_ptr() as usize)?;
+    (offset + part.len() <= whole.len()).then_some(offset)
+}
+
+impl ParseError {
+    /// An error about `token`, which must be a slice of `input`. Its line and column are derived from where it is.
+    pub fn at(day: u8, input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let mut error = Self {
+            day,
+            line: 0,
+            column: 0,
+            text: token.to_string(),
+            expected: expected.into(),
+            source_line: String::new(),
+        };
+        if let Some(offset) = offset_in(input, token) {
+            let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+            error.line = input[..offset].matches('\n').count() + 1;
+            error.column = input[line_start..offset].chars().count() + 1;
+            error.source_line = input[line_start..]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string();
+        }
+        error
+    }
+
+    /// Locates an `error` of a [`scan!`] or [`ints`](helpers::parse::ints) call on `line`, a line of `input`.
+    pub fn from_token(day: u8, input: &str, line: &str, error: helpers::parse::TokenError) -> Self {
+        let mut located = Self::at(day, input, line, error.expected);
+        located.column = error.column;
+        located.text = error.text;
+        located
+    }
+
+    /// Locates an `error` of [`Grid::parse`](helpers::grid::Grid::parse) on `input`.
+    pub fn from_grid(day: u8, input: &str, error: helpers::grid::GridError) -> Self {
+        use helpers::grid::GridError;
+
+        let row = |line: usize| input.lines().nth(line - 1).unwrap_or_default().trim_end();
+        let (line, column, text, expected) = match error {
+            GridError::Empty => (1, 1, String::new(), "at least one row".to_string()),
+            // a long row points at its extra cells, a short one just past its end.
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } if found > expected => (
+                line,
+                expected + 1,
+                row(line).chars().skip(expected).collect(),
+                format!("the end of the row after {expected} cells"),
+            ),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => (
+                line,
+                found + 1,
+                String::new(),
+                format!("{} more cells", expected - found),
+            ),
+            GridError::InvalidChar { line, column, c } => (
+                line,
+                column,
+                c.to_string(),
+                "a cell of the grid".to_string(),
+            ),
+        };
+        let mut located = Self::at(day, input, row(line), expected);
+        located.line = line;
+        located.column = column;
+        located.text = text;
+        located
+    }
+}
+
+/// Shows the error with the line it is on, e.g.
+///
+/// ```text
+/// day 02, line 3, column 3: expected one of "X", "Y" or "Z", found "W"
+///   |
+/// 3 | A W
+///   |   ^
+/// ```
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {:02}", self.day)?;
+        if self.line > 0 {
+            write!(f, ", line {}, column {}", self.line, self.column)?;
+        }
+        if self.text.is_empty() {
+            write!(f, ": expected {}, found nothing", self.expected)?;
+        } else {
+            write!(f, ": expected {}, found {:?}", self.expected, self.text)?;
+        }
+
+        if self.line > 0 {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let marker = "^".repeat(self.text.chars().count().max(1));
+            let indent = " ".repeat(self.column.saturating_sub(1));
+            write!(
+                f,
+                "\n{gutter} |\n{} | {}\n{gutter} | {indent}{marker}",
+                self.line, self.source_line
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Type-erased registry entry for a [`Solution`].
+pub struct Day {
+    pub day: u8,
+    pub part_one: fn(&str) -> PartResult,
+    pub part_two: fn(&str) -> PartResult,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            part_one: |input| run_part(S::part_one, input),
+            part_two: |input| run_part(S::part_two, input),
+        }
+    }
+
+    pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+        Year::find(year)?.days.iter().find(|d| d.day == day)
+    }
+}
+
+/// The solved days of one event. Listed in [`solutions::YEARS`].
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub const fn of(year: u16, days: &'static [Day]) -> Self {
+        Self { year, days }
+    }
+
+    pub fn find(year: u16) -> Option<&'static Year> {
+        solutions::YEARS.iter().find(|y| y.year == year)
+    }
+}
+
+pub struct PartResult {
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    /// Heap usage of the run, counted if the `alloc-stats` feature is enabled.
+    pub allocs: Option<AllocStats>,
+    /// Set if the solver did not return.
+    pub error: Option<PartError>,
+}
+
+impl PartResult {
+    pub fn failed(error: PartError) -> Self {
+        Self {
+            answer: None,
+            elapsed: match error {
+                PartError::TimedOut(timeout) => timeout,
+                PartError::Panicked(_) | PartError::InvalidInput(_) => Duration::ZERO,
+            },
+            allocs: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Why a solver did not return an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartError {
+    /// The solver panicked with this message.
+    Panicked(String),
+    /// The solver was still running after this long. It is left running in the background.
+    TimedOut(Duration),
+    /// The solver could not read its input.
+    InvalidInput(ParseError),
+}
+
+impl Display for PartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartError::Panicked(message) => write!(f, "panicked: {message}"),
+            PartError::TimedOut(timeout) => write!(f, "timed out after {timeout:.2?}"),
+            PartError::InvalidInput(error) => write!(f, "invalid input: {error}"),
+        }
+    }
+}
+
+pub fn run_part<R: IntoAnswer>(func: impl FnOnce(&str) -> R, input: &str) -> PartResult {
+    let ((result, elapsed), allocs) = alloc_stats::measure(|| {
+        let timer = Instant::now();
+        let result = func(input);
+        (result, timer.elapsed())
+    });
+    let (answer, error) = match result.into_answer() {
+        Ok(answer) => (answer.map(|r| r.to_string()), None),
+        Err(e) => (None, Some(PartError::InvalidInput(e))),
+    };
+    PartResult {
+        answer,
+        elapsed,
+        allocs,
+        error,
+    }
+}
+
+/// Allocation statistics appended to the timing of a part, if they were counted.
+pub fn format_allocs(allocs: Option<&AllocStats>) -> String {
+    allocs.map_or_else(String::new, |allocs| format!(", {allocs}"))
+}
+
+/// Prints the answer of a part, marked with ✓ or ✗ if the `expected` answer is known.
+pub fn print_result(part: u8, result: &PartResult, expected: Option<&str>) {
+    println!("🎄 {ANSI_BOLD}Part {part}{ANSI_RESET} 🎄");
+    if let Some(error) = &result.error {
+        println!("{ANSI_BOLD}{error}{ANSI_RESET}");
+        return;
+    }
+    match &result.answer {
+        Some(answer) => {
+            println!(
+                "{} {}(elapsed: {:.2?}{}){}{}",
+                answer,
+                ANSI_ITALIC,
+                result.elapsed,
+                format_allocs(result.allocs.as_ref()),
+                ANSI_RESET,
+                answers::mark(answer, expected)
+            );
+        }
+        None => {
+            println!("not solved.")
+        }
+    }
+}
+
+/// Command line options shared by `solve!` and the day binaries.
+pub struct Options {
+    /// Year of the solutions and data files. Defaults to [`default_year`].
+    pub year: u16,
+    /// Number of measured iterations per part. `None` times a single run.
+    pub bench: Option<u32>,
+    /// Unmeasured runs before benchmarking. Defaults to [`bench::default_warmup`].
+    pub warmup: Option<u32>,
+    pub format: Format,
+    pub input: InputSource,
+    /// Longest time a single run of a part may take. `None` waits forever.
+    pub timeout: Option<Duration>,
+}
+
+/// Default of `--timeout`. Every puzzle has a solution that takes at most a few seconds.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+impl Options {
+    pub fn parse(mut args: pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let input_path: Option<PathBuf> = args.opt_value_from_str("--input")?;
+        let example = args.contains("--example");
+        let bench = args.opt_value_from_str("--bench")?;
+        if bench == Some(0) {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "`--bench` needs at least 1 iteration".into(),
+            });
+        }
+
+        let mut options = Self {
+            year: args
+                .opt_value_from_str(["-y", "--year"])?
+                .unwrap_or_else(default_year),
+            bench,
+            warmup: args.opt_value_from_str("--warmup")?,
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            input: InputSource::Input,
+            timeout: match args.opt_value_from_str::<_, f64>("--timeout")? {
+                None => Some(DEFAULT_TIMEOUT),
+                // `--timeout 0` disables the timeout.
+                Some(seconds) if seconds > 0.0 => Some(Duration::from_secs_f64(seconds)),
+                Some(_) => None,
+            },
+        };
+
+        // the number of `--example [n]` is a free argument, so it is parsed after all flags.
+        options.input = match (input_path, example) {
+            (Some(_), true) => {
+                return Err(pico_args::Error::ArgumentParsingFailed {
+                    cause: "`--input` and `--example` can't be combined".into(),
+                })
+            }
+            (Some(path), false) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), false) => InputSource::File(path),
+            (None, true) => InputSource::Example(args.opt_free_from_str()?.unwrap_or(1)),
+            (None, false) => InputSource::Input,
+        };
+
+        Ok(options)
+    }
+
+    /// Parses the process arguments, exiting with a message if they are invalid.
+    pub fn from_env() -> Self {
+        match Self::parse(pico_args::Arguments::from_env()) {
+            Ok(options) => options,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Runs a part once, or benchmarks it if `options.bench` is set, without printing anything.
+/// When benchmarking, the returned `elapsed` is the median of all measured runs.
+/// The part runs on a worker thread, so a panic is reported as [`PartError::Panicked`] instead of
+/// aborting the binary. If it runs longer than `options.timeout` per run, [`PartError::TimedOut`] is returned.
+pub fn run_solver(
+    solver: impl Fn(&str) -> PartResult + Send + 'static,
+    input: &str,
+    options: &Options,
+) -> (PartResult, Option<bench::Stats>) {
+    let input: Arc<str> = input.into();
+    let bench = options.bench.map(|iterations| {
+        let warmup = options
+            .warmup
+            .unwrap_or_else(|| bench::default_warmup(iterations));
+        (iterations, warmup)
+    });
+
+    let (sender, receiver) = mpsc::channel();
+    let worker = thread::Builder::new()
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let _ = sender.send(match bench {
+                None => (solver(&input), None),
+                Some((iterations, warmup)) => {
+                    let (mut result, stats) = bench::bench(solver, &input, iterations, warmup);
+                    if let Some(stats) = &stats {
+                        result.elapsed = stats.median;
+                    }
+                    (result, stats)
                 }
+            });
+        });
+    let worker = match worker {
+        Ok(worker) => worker,
+        Err(e) => {
+            let message = format!("could not start worker thread: {e}");
+            return (PartResult::failed(PartError::Panicked(message)), None);
+        }
+    };
+
+    let received = match options.timeout {
+        Some(timeout) => {
+            let runs = bench.map_or(1, |(iterations, warmup)| iterations + warmup);
+            let timeout = timeout.saturating_mul(runs);
+            receiver.recv_timeout(timeout).map_err(|e| (e, timeout))
+        }
+        None => receiver
+            .recv()
+            .map_err(|_| (RecvTimeoutError::Disconnected, Duration::ZERO)),
+    };
+
+    match received {
+        Ok(result) => result,
+        Err((RecvTimeoutError::Timeout, timeout)) => {
+            (PartResult::failed(PartError::TimedOut(timeout)), None)
+        }
+        Err((RecvTimeoutError::Disconnected, _)) => {
+            let message = match worker.join() {
+                Err(payload) => panic_message(payload.as_ref()),
+                Ok(()) => "the solver stopped without a result".into(),
+            };
+            (PartResult::failed(PartError::Panicked(message)), None)
+        }
+    }
+}
+
+/// Stack of the threads solvers run on. Larger than the default of spawned threads so recursive solutions
+/// don't overflow earlier than they would on the main thread.
+const SOLVER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+/// Prints the answer of a part in `options.format`, with its benchmark `stats` if there are any.
+/// The answer is checked against `src/answers/YYYY/DD.toml` if it exists and the input is the real input.
+pub fn print_part(
+    day: u8,
+    part: u8,
+    result: &PartResult,
+    stats: Option<&bench::Stats>,
+    options: &Options,
+) {
+    if options.format == Format::Pretty {
+        let answers = match options.input {
+            InputSource::Input => Answers::load(options.year, day).unwrap_or_else(|e| {
+                eprintln!("Failed to read accepted answers: {e}");
+                Answers::default()
+            }),
+            _ => Answers::default(),
+        };
+        let expected = answers.get(part);
+
+        match (&result.answer, stats) {
+            (Some(answer), Some(stats)) => {
+                bench::print_stats(part, answer, stats, result.allocs.as_ref(), expected)
             }
+            _ => print_result(part, result, expected),
+        }
+    }
+
+    let record = Record::new(day, part, result);
+    record.print(options.format);
+
+    if let Some(path) = env::var_os(RECORDS_ENV) {
+        let written = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", record.to_csv()));
+        if let Err(e) = written {
+            eprintln!("Failed to write to {RECORDS_ENV}: {e}");
         }
+    }
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+/// Solves one part and prints its answer in `options.format`, benchmarking it if `options.bench` is set.
+pub fn solve_part(
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> PartResult + Send + 'static,
+    input: &str,
+    options: &Options,
+) -> PartResult {
+    let (result, stats) = run_solver(solver, input, options);
+    print_part(day, part, &result, stats.as_ref(), options);
+    result
+}
+
+/// Solves a part with `solver` and prints the result.
+/// The day reported in structured output is taken from the name of the calling `src/bin/DD.rs` binary.
+#[macro_export]
+macro_rules! solve {
+    ($part:expr, $solver:expr, $input:expr) => {{
+        let day = option_env!("CARGO_BIN_NAME")
+            .and_then(|name| name.parse().ok())
+            .unwrap_or(0);
+        $crate::solve_part(
+            day,
+            $part,
+            |input| $crate::run_part($solver, input),
+            $input,
+            &$crate::Options::from_env(),
+        );
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Entry point of the `src/bin/DD.rs` binaries: solves both parts of `day` against its input.
+/// Pass `-- --year <year>` to run another year than [`default_year`],
+/// `-- --input <path>`, `-- --input -` (stdin) or `-- --example [n]` to solve another input,
+/// `-- --bench <iterations>` to benchmark each part instead of timing a single run,
+/// `-- --timeout <seconds>` to change how long a part may run (`0` waits forever),
+/// and `-- --format json|csv` for machine-readable output.
+pub fn run_day(day: u8) {
+    let options = Options::from_env();
+
+    let Some(solution) = Day::find(options.year, day) else {
+        eprintln!(
+            "Day {day} is not registered in `src/solutions/year{}/mod.rs`.",
+            options.year
+        );
+        process::exit(1);
+    };
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+    if options.format == Format::Csv {
+        println!("{CSV_HEADER}");
+    }
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let input = match options.input.read(options.year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            print_read_error(&e, options.input.folder(), options.year, day);
+            process::exit(1);
+        }
+    };
+    let input = &input;
+    let results = [
+        solve_part(day, 1, solution.part_one, input, &options),
+        solve_part(day, 2, solution.part_two, input, &options),
+    ];
+
+    if results.iter().any(|result| result.error.is_some()) {
+        process::exit(1);
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Why a data file could not be loaded by [`read_file`].
+#[derive(Debug)]
+pub enum ReadFileError {
+    NotFound(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    /// The file contains nothing but whitespace, e.g. the placeholder created by `cargo scaffold`.
+    Empty(PathBuf),
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
+impl ReadFileError {
+    /// A command that creates the missing file, if there is one.
+    pub fn hint(&self, folder: &str, year: u16, day: u8) -> Option<String> {
+        if let ReadFileError::Unreadable(..) = self {
+            return None;
+        }
+        let year_flag = if year == default_year() {
+            String::new()
         } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+            format!(" --year {year}")
+        };
+        match folder {
+            "inputs" => Some(format!(
+                "run `cargo download {day}{year_flag}` to download your input."
+            )),
+            "examples" => Some(format!(
+                "run `cargo extract {day}{year_flag}` or paste the example input into the file."
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadFileError::NotFound(path) => write!(f, "\"{}\" does not exist.", path.display()),
+            ReadFileError::Unreadable(path, e) => {
+                write!(f, "could not read \"{}\": {e}", path.display())
             }
+            ReadFileError::Empty(path) => write!(f, "\"{}\" is empty.", path.display()),
         }
-    })
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Reads `src/{folder}/{year}/{day}.txt`.
+pub fn read_file(folder: &str, year: u16, day: u8) -> Result<String, ReadFileError> {
+    read_path(
+        Path::new("src")
+            .join(folder)
+            .join(year.to_string())
+            .join(format!("{day:02}.txt")),
+    )
+}
+
+fn read_path(path: PathBuf) -> Result<String, ReadFileError> {
+    let contents = fs::read_to_string(&path);
+    check_contents(path, contents)
+}
+
+fn check_contents(path: PathBuf, contents: io::Result<String>) -> Result<String, ReadFileError> {
+    match contents {
+        Ok(contents) if contents.trim().is_empty() => Err(ReadFileError::Empty(path)),
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ReadFileError::NotFound(path)),
+        Err(e) => Err(ReadFileError::Unreadable(path, e)),
+    }
+}
+
+/// Where the day binaries read the puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Your input in `src/inputs/YYYY/DD.txt`.
+    Input,
+    /// Example `n` in `src/examples/YYYY`: `DD.txt` for the first, `DD-n.txt` for the others.
+    Example(u8),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self, year: u16, day: u8) -> Result<String, ReadFileError> {
+        match self {
+            InputSource::Input => read_file("inputs", year, day),
+            InputSource::Example(1) => read_file("examples", year, day),
+            InputSource::Example(n) => read_path(
+                Path::new("src")
+                    .join("examples")
+                    .join(year.to_string())
+                    .join(format!("{day:02}-{n}.txt")),
+            ),
+            InputSource::File(path) => read_path(path.clone()),
+            InputSource::Stdin => check_contents("<stdin>".into(), io::read_to_string(io::stdin())),
+        }
+    }
+
+    /// The data folder of the source, used to hint at how to create a missing file.
+    pub fn folder(&self) -> &'static str {
+        match self {
+            InputSource::Input => "inputs",
+            InputSource::Example(_) => "examples",
+            InputSource::File(_) | InputSource::Stdin => "",
+        }
+    }
+}
+
+/// Prints why a data file could not be loaded and how to fix it.
+pub fn print_read_error(e: &ReadFileError, folder: &str, year: u16, day: u8) {
+    eprintln!("Failed to read input: {e}");
+    if let Some(hint) = e.hint(folder, year, day) {
+        eprintln!("Hint: {hint}");
+    }
 }
 
 pub mod aoc_cli {
@@ -126,17 +770,17 @@ pub mod aoc_cli {
         Ok(())
     }
 
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
+    pub fn read(day: u8, year: u16) -> Result<Output, AocCliError> {
         let args = build_args("read", &[], day, year);
         call_aoc_cli(&args)
     }
 
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
+    pub fn download(day: u8, year: u16) -> Result<Output, AocCliError> {
+        let input_path = get_input_path(year, day);
 
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
+        let puzzle_path = get_puzzle_path(year, day);
+        create_dir_all(format!("src/inputs/{year}")).map_err(|_| AocCliError::IoError)?;
+        create_dir_all(format!("src/puzzles/{year}")).map_err(|_| AocCliError::IoError)?;
 
         let args = build_args(
             "download",
@@ -163,23 +807,21 @@ pub mod aoc_cli {
         }
     }
 
-    fn get_input_path(day: u8) -> String {
+    pub fn get_input_path(year: u16, day: u8) -> String {
         let day_padded = format!("{day:02}");
-        format!("src/inputs/{day_padded}.txt")
+        format!("src/inputs/{year}/{day_padded}.txt")
     }
 
-    fn get_puzzle_path(day: u8) -> String {
+    pub fn get_puzzle_path(year: u16, day: u8) -> String {
         let day_padded = format!("{day:02}");
-        format!("src/puzzles/{day_padded}.md")
+        format!("src/puzzles/{year}/{day_padded}.md")
     }
 
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
+    fn build_args(command: &str, args: &[String], day: u8, year: u16) -> Vec<String> {
         let mut cmd_args = args.to_vec();
 
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
-        }
+        cmd_args.push("--year".into());
+        cmd_args.push(year.to_string());
 
         cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
 
@@ -205,30 +847,121 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_read_file() {
+        assert!(read_file("examples", 2022, 1).is_ok());
+
+        let e = read_file("examples", 1999, 1).unwrap_err();
+        assert!(matches!(e, ReadFileError::NotFound(_)));
+        assert_eq!(
+            e.hint("inputs", 1999, 1),
+            Some("run `cargo download 1 --year 1999` to download your input.".into())
         );
+    }
+
+    fn parse_options(args: &[&str]) -> Result<Options, pico_args::Error> {
+        Options::parse(pico_args::Arguments::from_vec(
+            args.iter().map(Into::into).collect(),
+        ))
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+    #[test]
+    fn test_parse_input_source() {
+        assert_eq!(parse_options(&[]).unwrap().input, InputSource::Input);
+        assert_eq!(
+            parse_options(&["--example"]).unwrap().input,
+            InputSource::Example(1)
+        );
+        assert_eq!(
+            parse_options(&["--example", "2", "--bench", "5"])
+                .unwrap()
+                .input,
+            InputSource::Example(2)
+        );
+        assert_eq!(
+            parse_options(&["--input", "-"]).unwrap().input,
+            InputSource::Stdin
         );
+        assert_eq!(
+            parse_options(&["--input", "edge.txt"]).unwrap().input,
+            InputSource::File("edge.txt".into())
+        );
+        assert!(parse_options(&["--input", "edge.txt", "--example"]).is_err());
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_options(&[]).unwrap().timeout, Some(DEFAULT_TIMEOUT));
+        assert_eq!(
+            parse_options(&["--timeout", "1.5"]).unwrap().timeout,
+            Some(Duration::from_millis(1500))
         );
+        assert_eq!(parse_options(&["--timeout", "0"]).unwrap().timeout, None);
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(parse_options(&["--bench", "3"]).unwrap().bench, Some(3));
+        assert!(parse_options(&["--bench", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_run_solver_isolates_failures() {
+        let options = parse_options(&["--timeout", "0.05"]).unwrap();
+
+        let (result, _) = run_solver(
+            |input| run_part(|input: &str| Some(input.parse::<u32>().unwrap()), input),
+            "x",
+            &options,
+        );
+        match result.error {
+            Some(PartError::Panicked(message)) => assert!(message.contains("InvalidDigit")),
+            other => panic!("expected a panic, got {other:?}"),
+        }
+
+        let (result, _) = run_solver(
+            |input| {
+                thread::sleep(Duration::from_secs(1));
+                run_part(|input: &str| Some(input.len()), input)
+            },
+            "x",
+            &options,
+        );
+        assert_eq!(
+            result.error,
+            Some(PartError::TimedOut(Duration::from_millis(50)))
+        );
+
+        let (result, _) = run_solver(
+            |input| run_part(|input: &str| Some(input.len()), input),
+            "abc",
+            &options,
+        );
+        assert_eq!(result.answer.as_deref(), Some("3"));
+        assert_eq!(result.error, None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "1 2\n3 x4\n";
+        let error = ParseError::at(5, input, &input[6..8], "a number");
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(
+            error.to_string(),
+            "day 05, line 2, column 3: expected a number, found \"x4\"\n  |\n2 | 3 x4\n  |   ^^"
+        );
+
+        let error = ParseError::at(5, input, "elsewhere", "a number");
+        assert_eq!(error.line, 0);
+        assert_eq!(
+            error.to_string(),
+            "day 05: expected a number, found \"elsewhere\""
+        );
+
+        let result = run_part(
+            |input: &str| -> Answer<u32> { Err(ParseError::at(5, input, input, "a number")) },
+            input,
         );
+        assert_eq!(result.answer, None);
+        assert!(matches!(result.error, Some(PartError::InvalidInput(_))));
     }
 }
@@ -2,44 +2,414 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::{self, Answers};
+use advent_of_code::history::{self, Entry};
+use advent_of_code::report::{self, Format, ParsedRecord, Status, CSV_HEADER};
+use advent_of_code::{InputSource, Options, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, RECORDS_ENV};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    ffi::OsString,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{self, Command, Output, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+/// Flags of `cargo all` itself, everything else is passed on to the executable of every day.
+const OWN_FLAGS: [&str; 2] = ["--save", "--compare"];
+const OWN_OPTIONS: [&str; 3] = ["--threshold", "-j", "--jobs"];
+
+struct Args {
+    /// Append this run's timings to the history file.
+    save: bool,
+    /// Report parts that got slower than the latest timings in the history file.
+    compare: bool,
+    threshold: f64,
+    /// Number of days solved at the same time.
+    jobs: usize,
+    options: Options,
+    /// The arguments passed on to the executable of every day.
+    day_args: Vec<OsString>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let raw: Vec<OsString> = env::args_os().skip(1).collect();
+    let day_args = day_args(&raw);
+
+    let mut args = pico_args::Arguments::from_vec(raw);
+    let save = args.contains("--save");
+    let compare = args.contains("--compare");
+    let threshold = args
+        .opt_value_from_str("--threshold")?
+        .unwrap_or(history::DEFAULT_THRESHOLD);
+    let jobs: Option<usize> = args.opt_value_from_str(["-j", "--jobs"])?;
+    let options = Options::parse(args)?;
+
+    // days running side by side compete for the CPU, so timings that are kept or compared
+    // are measured one day at a time unless asked otherwise.
+    let jobs = jobs.unwrap_or_else(|| {
+        if save || compare || options.bench.is_some() {
+            1
+        } else {
+            thread::available_parallelism().map_or(1, |n| n.get())
+        }
+    });
+
+    Ok(Args {
+        save,
+        compare,
+        threshold,
+        jobs: jobs.max(1),
+        options,
+        day_args,
+    })
+}
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
+/// `args` without the flags and options that only `cargo all` understands.
+fn day_args(args: &[OsString]) -> Vec<OsString> {
+    let mut kept = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let text = arg.to_string_lossy();
+        let name = text.split('=').next().unwrap_or_default();
+        if OWN_FLAGS.contains(&name) {
+            continue;
+        }
+        if OWN_OPTIONS.contains(&name) {
+            if !text.contains('=') {
+                args.next();
             }
+            continue;
+        }
+        kept.push(arg.clone());
+    }
+    kept
+}
+
+/// Builds the executables of all days once, with the profile and features of this one.
+fn build_days() -> bool {
+    let mut cmd = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    cmd.args(["build", "--bins", "--quiet"]);
+    // `cargo run` describes this package in the environment, and build scripts that watch these
+    // variables would otherwise be rerun by every build, this one and the next `cargo all`.
+    for (name, _) in env::vars_os() {
+        let name = name.to_string_lossy();
+        if name.starts_with("CARGO_PKG_")
+            || [
+                "CARGO_MANIFEST_DIR",
+                "CARGO_MANIFEST_PATH",
+                "CARGO_CRATE_NAME",
+                "CARGO_BIN_NAME",
+                "CARGO_PRIMARY_PACKAGE",
+            ]
+            .contains(&&*name)
+        {
+            cmd.env_remove(&*name);
+        }
+    }
+    if !cfg!(debug_assertions) {
+        cmd.arg("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        cmd.args(["--features", "alloc-stats"]);
+    }
+    match cmd.status() {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("Failed to run cargo build: {e}");
+            false
+        }
+    }
+}
+
+/// The days of `year` that have an executable in `src/bin`.
+fn solved_days(year: &Year) -> io::Result<Vec<u8>> {
+    let mut bins = BTreeSet::new();
+    for entry in fs::read_dir("src/bin")? {
+        let path = entry?.path();
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if stem.len() == 2 && stem.bytes().all(|b| b.is_ascii_digit()) {
+            bins.insert(stem.parse::<u8>().unwrap_or_default());
+        }
+    }
+    Ok(year
+        .days
+        .iter()
+        .map(|solution| solution.day)
+        .filter(|day| bins.contains(day))
+        .collect())
+}
+
+/// What the executable of a day printed, and a record of every part it ran.
+type DayRun = Result<(Output, Vec<ParsedRecord>), String>;
+
+/// Runs the executable of `day` in `dir`, which writes its records to a file of its own.
+fn run_day(day: u8, dir: &Path, args: &[OsString]) -> DayRun {
+    let records: PathBuf =
+        env::temp_dir().join(format!("aoc-records-{}-{day:02}.csv", process::id()));
+    let bin = dir.join(format!("{day:02}{}", env::consts::EXE_SUFFIX));
+
+    let output = Command::new(&bin)
+        .args(args)
+        .env(RECORDS_ENV, &records)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to run \"{}\": {e}", bin.display()));
+    let text = match fs::read_to_string(&records) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Failed to read the records of day {day:02}: {e}")),
+    };
+    let _ = fs::remove_file(&records);
+
+    let records = report::parse_csv(&text)
+        .map_err(|e| format!("Failed to read the records of day {day:02}: {e}"))?;
+    Ok((output?, records))
+}
+
+/// Runs the executables of `days` in up to `jobs` processes at a time and passes each result to
+/// `report`, in the order of `days`.
+fn run_days(
+    days: &[u8],
+    dir: &Path,
+    args: &[OsString],
+    jobs: usize,
+    mut report: impl FnMut(u8, DayRun),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+                if sender.send((index, run_day(day, dir, args))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // results arrive in the order the days finish, hold them back until all earlier days are reported.
+        let mut pending = BTreeMap::new();
+        let mut reported = 0;
+        for (index, run) in receiver {
+            pending.insert(index, run);
+            while let Some(run) = pending.remove(&reported) {
+                report(days[reported], run);
+                reported += 1;
+            }
+        }
+    });
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+    let options = &args.options;
+
+    if matches!(options.input, InputSource::File(_) | InputSource::Stdin) {
+        eprintln!("`--input` selects the input of a single day, use it with `cargo solve`.");
+        process::exit(1);
+    }
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+    if options.input != InputSource::Input && (args.save || args.compare) {
+        eprintln!("`--save` and `--compare` only track timings of your inputs.");
+        process::exit(1);
+    }
 
+    let Some(year) = Year::find(options.year) else {
+        eprintln!(
+            "No solutions of {} are registered in `src/solutions/mod.rs`.",
+            options.year
+        );
+        process::exit(1);
+    };
+
+    if !build_days() {
+        eprintln!("Failed to build the solutions.");
+        process::exit(1);
+    }
+
+    let days = match solved_days(year) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Failed to list the solutions in \"src/bin\": {e}");
+            process::exit(1);
+        }
+    };
+
+    // `cargo build` puts the executables of all days next to this one.
+    let dir = match env::current_exe() {
+        Ok(exe) => exe.parent().map(Path::to_path_buf).unwrap_or_default(),
+        Err(e) => {
+            eprintln!("Failed to locate the executables of the solutions: {e}");
+            process::exit(1);
+        }
+    };
+
+    if options.format == Format::Csv {
+        println!("{CSV_HEADER}");
+    }
+
+    let mut entries = Vec::new();
+    let mut wrong_answers = 0;
+    let mut statuses: BTreeMap<Status, usize> = BTreeMap::new();
+    let mut unfinished = 0;
+
+    let mut total = Duration::ZERO;
+
+    run_days(&days, &dir, &args.day_args, args.jobs, |day, run| {
+        if options.format == Format::Pretty {
             println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
+            println!("{ANSI_BOLD}| Day {:02} |{ANSI_RESET}", day);
             println!("----------");
+        }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+        let (output, records) = match run {
+            Ok(run) => run,
+            Err(e) => {
+                eprintln!("{e}");
+                unfinished += 1;
+                return;
+            }
+        };
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
+        // every day prints its own csv header, only the one above is kept.
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stdout = match options.format {
+            Format::Csv => stdout
+                .strip_prefix(CSV_HEADER)
+                .map_or(&*stdout, |rest| rest.trim_start_matches(['\r', '\n'])),
+            _ => &stdout,
+        };
+        print!("{stdout}");
+        let _ = io::stdout().flush();
+        let _ = io::stderr().write_all(&output.stderr);
+
+        // a day exits with a code once its parts are done, e.g. on a missing input; without one it crashed.
+        if output.status.code().is_none() {
+            eprintln!(
+                "{ANSI_BOLD}Day {:02}{ANSI_RESET} was terminated: {}",
+                day, output.status
             );
+            unfinished += 1;
+        }
+
+        let answers = match options.input {
+            InputSource::Input => Answers::load(year.year, day).unwrap_or_default(),
+            _ => Answers::default(),
+        };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+        for record in records {
+            *statuses.entry(record.status).or_default() += 1;
+
+            if let Some(answer) = &record.answer {
+                entries.push(Entry::new(day, record.part, record.elapsed_ns));
+                total += Duration::from_nanos(record.elapsed_ns as u64);
+
+                if answers::verify(answer, answers.get(record.part)) == Some(false) {
+                    wrong_answers += 1;
+                }
             }
-        })
+        }
+    });
+
+    if options.format == Format::Pretty {
+        println!(
+            "{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+            total.as_secs_f64() * 1000_f64
+        );
+    }
+
+    let failed: usize = [Status::Panicked, Status::TimedOut, Status::InvalidInput]
+        .iter()
+        .filter_map(|status| statuses.get(status))
         .sum();
+    if options.format == Format::Pretty || failed > 0 {
+        let summary: Vec<String> = statuses
+            .iter()
+            .map(|(status, count)| format!("{count} {status}"))
+            .collect();
+        eprintln!("{ANSI_BOLD}Parts:{ANSI_RESET} {}", summary.join(", "));
+    }
+
+    if wrong_answers > 0 {
+        eprintln!(
+            "{ANSI_BOLD}{wrong_answers} answer(s) differ from the accepted answers in \"src/answers/{}\".{ANSI_RESET}",
+            year.year
+        );
+    }
+
+    let history_path = history::get_history_path(year.year);
+    let history_path = Path::new(&history_path);
+    let mut regressed = false;
+
+    if args.compare {
+        let history = match history::load(history_path) {
+            Ok(history) => history,
+            Err(e) => {
+                eprintln!("Failed to read benchmark history: {e}");
+                process::exit(1);
+            }
+        };
+        let baseline = history::baseline(&history, history::current_profile());
+
+        if baseline.is_empty() {
+            eprintln!(
+                "No {} timings in \"{}\" to compare against. Run `cargo all -- --save` first.",
+                history::current_profile(),
+                history_path.display()
+            );
+        } else {
+            let regressions = history::find_regressions(&baseline, &entries, args.threshold);
+            regressed = !regressions.is_empty();
+
+            if regressions.is_empty() {
+                eprintln!("No part got more than {}% slower.", args.threshold);
+            }
+
+            for r in regressions {
+                eprintln!(
+                    "{ANSI_BOLD}Day {:02} Part {}{ANSI_RESET} got slower: {:.2?} -> {:.2?} (+{:.1}%)",
+                    r.day,
+                    r.part,
+                    Duration::from_nanos(r.baseline_ns as u64),
+                    Duration::from_nanos(r.current_ns as u64),
+                    r.change
+                );
+            }
+        }
+    }
+
+    if args.save {
+        if let Err(e) = history::append(history_path, &entries) {
+            eprintln!("Failed to write benchmark history: {e}");
+            process::exit(1);
+        }
+        eprintln!("Saved timings to \"{}\".", history_path.display());
+    }
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    if regressed || wrong_answers > 0 || failed > 0 || unfinished > 0 {
+        process::exit(1);
+    }
 }
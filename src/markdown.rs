@@ -0,0 +1,224 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ANSI_BOLD, ANSI_RESET};
+use std::env;
+
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_CYAN: &str = "\x1b[36m";
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+
+const DEFAULT_WIDTH: usize = 80;
+const CODE_INDENT: &str = "    ";
+
+/// Width of the terminal, read from `COLUMNS`. Falls back to 80 columns.
+pub fn terminal_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .filter(|columns| *columns > 0)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Style {
+    emphasis: bool,
+    code: bool,
+    link: bool,
+}
+
+impl Style {
+    fn ansi(&self) -> String {
+        let mut codes = String::new();
+        if self.emphasis {
+            codes.push_str(ANSI_BOLD);
+            codes.push_str(ANSI_YELLOW);
+        }
+        if self.code {
+            codes.push_str(ANSI_CYAN);
+        }
+        if self.link {
+            codes.push_str(ANSI_UNDERLINE);
+        }
+        codes
+    }
+}
+
+type Word = Vec<(char, Style)>;
+
+/// Renders the markdown written by `cargo download` for reading in a terminal.
+/// Paragraphs and list items are wrapped to `width`, code blocks are indented and left as-is.
+pub fn render(markdown: &str, width: usize, color: bool) -> String {
+    let mut out = String::new();
+    let mut lines = markdown.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("```") {
+            for code in lines.by_ref() {
+                if code.starts_with("```") {
+                    break;
+                }
+                out.push_str(CODE_INDENT);
+                out.push_str(code);
+                out.push('\n');
+            }
+            out.push('\n');
+        } else if let Some(heading) = line.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            if color {
+                out.push_str(&format!("{ANSI_BOLD}{heading}{ANSI_RESET}\n\n"));
+            } else {
+                out.push_str(&format!("{heading}\n\n"));
+            }
+        } else if let Some(item) = line.strip_prefix("- ") {
+            wrap(&parse_inline(item), width, "  - ", "    ", color, &mut out);
+            if lines.peek().is_some_and(|next| next.trim().is_empty()) {
+                out.push('\n');
+            }
+        } else if !line.trim().is_empty() {
+            // paragraphs are a single line, but join continuation lines just in case.
+            let mut paragraph = line.to_string();
+            while let Some(next) = lines.next_if(|next| is_continuation(next)) {
+                paragraph.push(' ');
+                paragraph.push_str(next);
+            }
+            wrap(&parse_inline(&paragraph), width, "", "", color, &mut out);
+            out.push('\n');
+        }
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+fn is_continuation(line: &str) -> bool {
+    !line.trim().is_empty()
+        && !line.starts_with("```")
+        && !line.starts_with('#')
+        && !line.starts_with("- ")
+}
+
+/// Splits a line into words of styled characters, dropping the markdown markers.
+fn parse_inline(text: &str) -> Vec<Word> {
+    let mut words = vec![Word::new()];
+    let mut style = Style::default();
+    let mut chars = text.chars().peekable();
+    let mut link_target = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => style.code = !style.code,
+            '*' if !style.code => style.emphasis = !style.emphasis,
+            '[' if !style.code => style.link = true,
+            ']' if style.link && chars.peek() == Some(&'(') => {
+                style.link = false;
+                link_target = true;
+            }
+            ')' if link_target => link_target = false,
+            _ if link_target => {}
+            c if c.is_whitespace() => {
+                if !words.last().is_some_and(|w| w.is_empty()) {
+                    words.push(Word::new());
+                }
+            }
+            c => words.last_mut().unwrap().push((c, style)),
+        }
+    }
+
+    words.retain(|w| !w.is_empty());
+    words
+}
+
+fn wrap(words: &[Word], width: usize, first: &str, indent: &str, color: bool, out: &mut String) {
+    let mut line_width = first.chars().count();
+    out.push_str(first);
+    let mut line_empty = true;
+
+    for word in words {
+        if !line_empty && line_width + 1 + word.len() > width {
+            out.push('\n');
+            out.push_str(indent);
+            line_width = indent.chars().count();
+            line_empty = true;
+        }
+
+        if !line_empty {
+            out.push(' ');
+            line_width += 1;
+        }
+
+        push_word(word, color, out);
+        line_width += word.len();
+        line_empty = false;
+    }
+
+    out.push('\n');
+}
+
+fn push_word(word: &Word, color: bool, out: &mut String) {
+    let mut current = Style::default();
+    for (c, style) in word {
+        if color && *style != current {
+            out.push_str(ANSI_RESET);
+            out.push_str(&style.ansi());
+            current = *style;
+        }
+        out.push(*c);
+    }
+    if color && current != Style::default() {
+        out.push_str(ANSI_RESET);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "## --- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of [Calories](/2022/day/1/input) contained by the various meals.
+
+```
+1000
+2000
+```
+
+- The first Elf is carrying `6000` Calories.
+- The second Elf is carrying *`24000`* Calories.
+
+In the example above, this is *24000*.
+";
+
+    #[test]
+    fn test_render_plain() {
+        assert_eq!(
+            render(PUZZLE, 30, false),
+            "--- Day 1: Calorie Counting ---
+
+The Elves take turns writing
+down the number of Calories
+contained by the various
+meals.
+
+    1000
+    2000
+
+  - The first Elf is carrying
+    6000 Calories.
+  - The second Elf is carrying
+    24000 Calories.
+
+In the example above, this is
+24000.
+"
+        );
+    }
+
+    #[test]
+    fn test_render_emphasis() {
+        assert_eq!(
+            render("this is *`24000`*.", 80, true),
+            format!("this is {ANSI_RESET}{ANSI_BOLD}{ANSI_YELLOW}{ANSI_CYAN}24000{ANSI_RESET}.\n")
+        );
+    }
+}
@@ -0,0 +1,315 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{PartError, PartResult};
+use std::{fmt::Display, str::FromStr};
+
+/// How results are written to stdout.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable output with colors.
+    #[default]
+    Pretty,
+    /// One JSON object per line.
+    Json,
+    /// Comma separated values with a header row.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!(
+                "unknown format {s:?}, expected pretty, json or csv"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Solved,
+    NotSolved,
+    Panicked,
+    TimedOut,
+    InvalidInput,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::NotSolved => write!(f, "not_solved"),
+            Status::Panicked => write!(f, "panicked"),
+            Status::TimedOut => write!(f, "timed_out"),
+            Status::InvalidInput => write!(f, "invalid_input"),
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Self::Solved),
+            "not_solved" => Ok(Self::NotSolved),
+            "panicked" => Ok(Self::Panicked),
+            "timed_out" => Ok(Self::TimedOut),
+            "invalid_input" => Ok(Self::InvalidInput),
+            _ => Err(format!("unknown status {s:?}")),
+        }
+    }
+}
+
+/// Machine-readable result of a single part.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<&'a str>,
+    pub elapsed_ns: u128,
+    pub status: Status,
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,elapsed_ns,status";
+
+impl<'a> Record<'a> {
+    pub fn new(day: u8, part: u8, result: &'a PartResult) -> Self {
+        Self {
+            day,
+            part,
+            answer: result.answer.as_deref(),
+            elapsed_ns: result.elapsed.as_nanos(),
+            status: match (&result.error, &result.answer) {
+                (Some(PartError::Panicked(_)), _) => Status::Panicked,
+                (Some(PartError::TimedOut(_)), _) => Status::TimedOut,
+                (Some(PartError::InvalidInput(_)), _) => Status::InvalidInput,
+                (None, Some(_)) => Status::Solved,
+                (None, None) => Status::NotSolved,
+            },
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let answer = match self.answer {
+            Some(answer) => format!("\"{}\"", escape_json(answer)),
+            None => "null".into(),
+        };
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"status\":\"{}\"}}",
+            self.day, self.part, answer, self.elapsed_ns, self.status
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            self.day,
+            self.part,
+            escape_csv(self.answer.unwrap_or_default()),
+            self.elapsed_ns,
+            self.status
+        )
+    }
+
+    /// Prints the record in a structured `format`. Does nothing for [`Format::Pretty`].
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::Pretty => {}
+            Format::Json => println!("{}", self.to_json()),
+            Format::Csv => println!("{}", self.to_csv()),
+        }
+    }
+}
+
+/// A [`Record`] read back from CSV rows written by [`Record::to_csv`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedRecord {
+    pub day: u8,
+    pub part: u8,
+    /// Only set for solved parts.
+    pub answer: Option<String>,
+    pub elapsed_ns: u128,
+    pub status: Status,
+}
+
+/// Reads the rows of `text`, which may start with [`CSV_HEADER`].
+pub fn parse_csv(text: &str) -> Result<Vec<ParsedRecord>, String> {
+    split_csv(text)
+        .into_iter()
+        .filter(|row| row.join(",") != CSV_HEADER)
+        .map(|row| {
+            let [day, part, answer, elapsed_ns, status] = row.as_slice() else {
+                return Err(format!("expected 5 fields, found {row:?}"));
+            };
+            let number = |field: &str| format!("invalid {field} in {row:?}");
+            let status: Status = status.parse()?;
+            Ok(ParsedRecord {
+                day: day.parse().map_err(|_| number("day"))?,
+                part: part.parse().map_err(|_| number("part"))?,
+                answer: (status == Status::Solved).then(|| answer.clone()),
+                elapsed_ns: elapsed_ns.parse().map_err(|_| number("elapsed_ns"))?,
+                status,
+            })
+        })
+        .collect()
+}
+
+/// Splits CSV into rows of fields. Quoted fields may contain commas, line breaks and `""` for a quote.
+fn split_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_record_to_json() {
+        let result = PartResult {
+            answer: Some("#.\n\"x\"".into()),
+            elapsed: Duration::from_nanos(74),
+            allocs: None,
+            error: None,
+        };
+        assert_eq!(
+            Record::new(10, 2, &result).to_json(),
+            r##"{"day":10,"part":2,"answer":"#.\n\"x\"","elapsed_ns":74,"status":"solved"}"##
+        );
+
+        let result = PartResult {
+            answer: None,
+            elapsed: Duration::from_micros(1),
+            allocs: None,
+            error: None,
+        };
+        assert_eq!(
+            Record::new(1, 1, &result).to_json(),
+            r#"{"day":1,"part":1,"answer":null,"elapsed_ns":1000,"status":"not_solved"}"#
+        );
+    }
+
+    #[test]
+    fn test_record_to_csv() {
+        let result = PartResult {
+            answer: Some("24000".into()),
+            elapsed: Duration::from_nanos(1_500),
+            allocs: None,
+            error: None,
+        };
+        assert_eq!(Record::new(1, 1, &result).to_csv(), "1,1,24000,1500,solved");
+
+        let result = PartResult {
+            answer: Some("a,\"b\"".into()),
+            elapsed: Duration::ZERO,
+            allocs: None,
+            error: None,
+        };
+        assert_eq!(
+            Record::new(5, 2, &result).to_csv(),
+            "5,2,\"a,\"\"b\"\"\",0,solved"
+        );
+    }
+
+    #[test]
+    fn test_record_status() {
+        let result = PartResult::failed(PartError::Panicked("oops".into()));
+        assert_eq!(Record::new(3, 1, &result).status, Status::Panicked);
+
+        let result = PartResult::failed(PartError::TimedOut(Duration::from_secs(2)));
+        let record = Record::new(3, 2, &result);
+        assert_eq!(record.status, Status::TimedOut);
+        assert_eq!(record.to_csv(), "3,2,,2000000000,timed_out");
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("pretty".parse(), Ok(Format::Pretty));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let solved = PartResult {
+            answer: Some("#.\n\"x\", y".into()),
+            elapsed: Duration::from_nanos(74),
+            allocs: None,
+            error: None,
+        };
+        let timed_out = PartResult::failed(PartError::TimedOut(Duration::from_secs(1)));
+        let text = format!(
+            "{CSV_HEADER}\n{}\n{}\n",
+            Record::new(3, 1, &solved).to_csv(),
+            Record::new(3, 2, &timed_out).to_csv()
+        );
+
+        let records = parse_csv(&text).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].answer.as_deref(), Some("#.\n\"x\", y"));
+        assert_eq!(records[0].elapsed_ns, 74);
+        assert_eq!((records[1].part, records[1].status), (2, Status::TimedOut));
+        assert_eq!(records[1].answer, None);
+
+        assert!(parse_csv("1,2,3").is_err());
+    }
+}
@@ -0,0 +1,9 @@
+/*
+ * Registry of solved years.
+ * Each year lists its days in `src/solutions/yearYYYY/mod.rs`. `cargo scaffold` adds new years here.
+ */
+use crate::Year;
+
+pub mod year2022;
+
+pub const YEARS: &[Year] = &[Year::of(2022, year2022::DAYS)];
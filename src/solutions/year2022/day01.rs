@@ -0,0 +1,62 @@
+use crate::helpers::parse;
+use crate::{Answer, ParseError, Solution};
+
+fn parse_calories(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut calories = parse::paragraphs(input)
+        .map(|elf| {
+            elf.split_whitespace()
+                .map(|item| {
+                    item.parse::<u32>().map_err(|_| {
+                        ParseError::at(Day01::DAY, input, item, "a number of calories")
+                    })
+                })
+                .sum()
+        })
+        .collect::<Result<Vec<u32>, _>>()?;
+    calories.sort();
+    Ok(calories)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Answer<u32> {
+        let calories = parse_calories(input)?;
+
+        Ok(calories.into_iter().last())
+    }
+
+    fn part_two(input: &str) -> Answer<u32> {
+        let calories = parse_calories(input)?;
+
+        Ok(Some(calories.into_iter().rev().take(3).sum()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 1).unwrap();
+        assert_eq!(Day01::part_one(&input), Ok(Some(24_000)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 1).unwrap();
+        assert_eq!(Day01::part_two(&input), Ok(Some(45_000)));
+    }
+
+    #[test]
+    fn test_invalid_calories() {
+        let error = Day01::part_one("1000\n2x00\n\n3000").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "2x00");
+    }
+}
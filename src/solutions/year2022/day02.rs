@@ -0,0 +1,202 @@
+use crate::{Answer, ParseError, Solution};
+use std::cmp::Ordering;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Turn(Move, Move);
+
+impl Turn {
+    fn instruction(self) -> Self {
+        let m = match self {
+            Turn(m, Move::Rock) => m.lose(),
+            Turn(m, Move::Paper) => m.draw(),
+            Turn(m, Move::Scissors) => m.win(),
+        };
+        Self(self.0, m)
+    }
+
+    fn resolve(self) -> u32 {
+        let points = match self {
+            Turn(x, y) if x < y => 6,
+            Turn(x, y) if x == y => 3,
+            Turn(x, y) if x > y => 0,
+            _ => unreachable!(),
+        };
+        points + self.1 as u32
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Move {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl Move {
+    fn win(&self) -> Self {
+        match self {
+            Move::Rock => Move::Paper,
+            Move::Paper => Move::Scissors,
+            Move::Scissors => Move::Rock,
+        }
+    }
+
+    fn draw(&self) -> Self {
+        *self
+    }
+
+    fn lose(&self) -> Self {
+        match self {
+            Move::Rock => Move::Scissors,
+            Move::Paper => Move::Rock,
+            Move::Scissors => Move::Paper,
+        }
+    }
+}
+
+impl Ord for Move {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Move::Rock, Move::Paper) => Ordering::Less,
+            (Move::Rock, Move::Scissors) => Ordering::Greater,
+            (Move::Paper, Move::Rock) => Ordering::Greater,
+            (Move::Paper, Move::Scissors) => Ordering::Less,
+            (Move::Scissors, Move::Rock) => Ordering::Less,
+            (Move::Scissors, Move::Paper) => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+impl PartialOrd for Move {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reads `token`, a slice of `input`, as a move.
+fn parse_move(input: &str, token: &str) -> Result<Move, ParseError> {
+    match token {
+        "A" | "X" => Ok(Move::Rock),
+        "B" | "Y" => Ok(Move::Paper),
+        "C" | "Z" => Ok(Move::Scissors),
+        _ => Err(ParseError::at(
+            Day02::DAY,
+            input,
+            token,
+            "one of A, B, C, X, Y or Z",
+        )),
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Turn>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (left, right) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(Day02::DAY, input, line, "two moves"))?;
+            Ok(Turn(parse_move(input, left)?, parse_move(input, right)?))
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Answer<u32> {
+        let turns = parse(input)?;
+
+        Ok(Some(turns.into_iter().map(Turn::resolve).sum()))
+    }
+
+    fn part_two(input: &str) -> Answer<u32> {
+        let turns = parse(input)?;
+
+        Ok(Some(
+            turns
+                .into_iter()
+                .map(|turn| turn.instruction().resolve())
+                .sum(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 2).unwrap();
+        assert_eq!(Day02::part_one(&input), Ok(Some(15)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 2).unwrap();
+        assert_eq!(Day02::part_two(&input), Ok(Some(12)));
+    }
+
+    #[test]
+    fn test_parse_move() {
+        assert_eq!(parse_move("A", "A"), Ok(Move::Rock));
+        assert_eq!(parse_move("X", "X"), Ok(Move::Rock));
+        assert_eq!(parse_move("B", "B"), Ok(Move::Paper));
+        assert_eq!(parse_move("Y", "Y"), Ok(Move::Paper));
+        assert_eq!(parse_move("C", "C"), Ok(Move::Scissors));
+        assert_eq!(parse_move("Z", "Z"), Ok(Move::Scissors));
+    }
+
+    #[test]
+    fn test_parse_turns() {
+        assert_eq!(
+            parse("A Y\nB Z\nC X"),
+            Ok(vec![
+                Turn(Move::Rock, Move::Paper),
+                Turn(Move::Paper, Move::Scissors),
+                Turn(Move::Scissors, Move::Rock),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_invalid_move() {
+        let error = Day02::part_one("A Y\nB X\nA W").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(
+            error.to_string(),
+            "day 02, line 3, column 3: expected one of A, B, C, X, Y or Z, found \"W\"\n  |\n3 | A W\n  |   ^"
+        );
+
+        let error = Day02::part_one("A Y\nBX").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_turn_resolve() {
+        assert_eq!(Turn(Move::Rock, Move::Paper).resolve(), 8);
+        assert_eq!(Turn(Move::Paper, Move::Rock).resolve(), 1);
+        assert_eq!(Turn(Move::Scissors, Move::Scissors).resolve(), 6);
+    }
+
+    #[test]
+    fn test_turn_instruction() {
+        assert_eq!(
+            Turn(Move::Rock, Move::Paper).instruction(),
+            Turn(Move::Rock, Move::Rock)
+        );
+        assert_eq!(
+            Turn(Move::Paper, Move::Rock).instruction(),
+            Turn(Move::Paper, Move::Rock)
+        );
+        assert_eq!(
+            Turn(Move::Scissors, Move::Scissors).instruction(),
+            Turn(Move::Scissors, Move::Rock)
+        );
+    }
+}
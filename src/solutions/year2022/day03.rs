@@ -0,0 +1,208 @@
+use crate::{Answer, ParseError, Solution};
+
+struct Rucksack<'a>(&'a str, &'a str);
+
+impl<'a> Rucksack<'a> {
+    fn new(value: &'a str) -> Self {
+        let (left, right) = value.split_at(value.len() / 2);
+        Self(left, right)
+    }
+
+    fn find_repeated_item(&self) -> Option<usize> {
+        let Rucksack(left, right) = self;
+        let checked = check_items(left);
+        right
+            .chars()
+            .map(item_to_priority_value)
+            .find(|priority| checked[*priority - 1])
+    }
+}
+
+struct RucksackGroup<'a>(&'a str, &'a str, &'a str);
+
+impl<'a> RucksackGroup<'a> {
+    fn find_badge(&self) -> Option<usize> {
+        let RucksackGroup(one, two, three) = self;
+        let checked_one = check_items(one);
+        let checked_two = check_items(two);
+        three
+            .chars()
+            .map(item_to_priority_value)
+            .find(|priority| checked_one[*priority - 1] && checked_two[*priority - 1])
+    }
+}
+
+fn check_items(items: &str) -> [bool; 52] {
+    let mut checked = [false; 52];
+    for item in items.chars().map(item_to_priority_value) {
+        checked[item - 1] = true;
+    }
+    checked
+}
+
+fn item_to_priority_value(item: char) -> usize {
+    let item = item as u8;
+    let priority = if item > b'Z' {
+        // 96 offsets 'a' to 1
+        item - 96
+    } else {
+        // 38 offsets 'A' to 27
+        item - 38
+    };
+    priority as usize
+}
+
+/// The rucksacks of `input`, one per line, checked to hold only letters.
+fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    input
+        .lines()
+        .map(|line| match line.find(|c: char| !c.is_ascii_alphabetic()) {
+            Some(i) => Err(ParseError::at(
+                Day03::DAY,
+                input,
+                &line[i..i + line[i..].chars().next().map_or(0, char::len_utf8)],
+                "an item from a to z or A to Z",
+            )),
+            None => Ok(line),
+        })
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Answer<u32> {
+        let rucksacks = parse(input)?;
+        if let Some(odd) = rucksacks.iter().find(|line| !line.len().is_multiple_of(2)) {
+            return Err(ParseError::at(
+                Day03::DAY,
+                input,
+                odd,
+                "an even number of items",
+            ));
+        }
+
+        let value = rucksacks
+            .into_iter()
+            .map(Rucksack::new)
+            .map(|rucksack| rucksack.find_repeated_item().unwrap_or(0) as u32)
+            .sum();
+
+        Ok(Some(value))
+    }
+
+    fn part_two(input: &str) -> Answer<u32> {
+        let rucksacks = parse(input)?;
+
+        let value = rucksacks
+            .chunks(3)
+            .map(|chunk| match chunk {
+                [one, two, three] => Ok(RucksackGroup(one, two, three)),
+                _ => Err(ParseError::at(
+                    Day03::DAY,
+                    input,
+                    &input[input.len()..],
+                    "groups of three rucksacks",
+                )),
+            })
+            .map(|group| group.map(|group| group.find_badge().unwrap_or(0) as u32))
+            .sum::<Result<u32, _>>()?;
+
+        Ok(Some(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 3).unwrap();
+        assert_eq!(Day03::part_one(&input), Ok(Some(157)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 3).unwrap();
+        assert_eq!(Day03::part_two(&input), Ok(Some(70)));
+    }
+
+    #[test]
+    fn test_find_repeated_item_in_rucksack() {
+        let value = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let rucksack = Rucksack::new(value);
+        assert_eq!(rucksack.find_repeated_item(), Some(16));
+
+        let value = "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL";
+        let rucksack = Rucksack::new(value);
+        assert_eq!(rucksack.find_repeated_item(), Some(38));
+
+        let value = "PmmdzqPrVvPwwTWBwg";
+        let rucksack = Rucksack::new(value);
+        assert_eq!(rucksack.find_repeated_item(), Some(42));
+
+        let value = "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn";
+        let rucksack = Rucksack::new(value);
+        assert_eq!(rucksack.find_repeated_item(), Some(22));
+
+        let value = "ttgJtRGJQctTZtZT";
+        let rucksack = Rucksack::new(value);
+        assert_eq!(rucksack.find_repeated_item(), Some(20));
+
+        let value = "CrZsJsPPZsGzwwsLwLmpwMDw";
+        let rucksack = Rucksack::new(value);
+        assert_eq!(rucksack.find_repeated_item(), Some(19));
+    }
+
+    #[test]
+    fn test_item_to_priority_value() {
+        assert_eq!(item_to_priority_value('a'), 1);
+        assert_eq!(item_to_priority_value('z'), 26);
+        assert_eq!(item_to_priority_value('A'), 27);
+        assert_eq!(item_to_priority_value('Z'), 52);
+    }
+
+    #[test]
+    fn test_check_items() {
+        let mut expected = [false; 52];
+        expected[4] = true;
+        expected[11] = true;
+        expected[14] = true;
+        expected[33] = true;
+        assert_eq!(check_items("Hello"), expected);
+    }
+
+    #[test]
+    fn test_find_badge_in_rucksack_group() {
+        let group = RucksackGroup(
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+        );
+        assert_eq!(group.find_badge(), Some(18));
+
+        let group = RucksackGroup(
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        );
+        assert_eq!(group.find_badge(), Some(52));
+    }
+
+    #[test]
+    fn test_invalid_rucksacks() {
+        let error = Day03::part_one("abCa\nab1b").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "1");
+
+        let error = Day03::part_one("abc").unwrap_err();
+        assert_eq!(error.expected, "an even number of items");
+
+        assert!(Day03::part_two("abCa\nCb\nzC\nab").is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::helpers::parse::TokenError;
+use crate::{scan, Answer, ParseError, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Assignment(usize, usize);
+
+#[derive(Debug, PartialEq, Eq)]
+struct AssignmentPair(Assignment, Assignment);
+
+impl AssignmentPair {
+    fn is_overlapping(&self) -> bool {
+        match self {
+            AssignmentPair(Assignment(a, _), Assignment(c, d)) if a == c || a == d => true,
+            AssignmentPair(Assignment(_, b), Assignment(c, d)) if b == c || b == d => true,
+            AssignmentPair(Assignment(a, b), Assignment(c, _)) if a <= c && b >= c => true,
+            AssignmentPair(Assignment(a, _), Assignment(c, d)) if a >= c && a <= d => true,
+            AssignmentPair(Assignment(a, b), Assignment(_, d)) if a <= d && b >= d => true,
+            AssignmentPair(Assignment(_, b), Assignment(c, d)) if b >= c && b <= d => true,
+            _ => self.is_fully_overlapping(),
+        }
+    }
+
+    fn is_fully_overlapping(&self) -> bool {
+        match self {
+            AssignmentPair(Assignment(a, b), Assignment(c, d)) if a <= c && b >= d => true,
+            AssignmentPair(Assignment(a, b), Assignment(c, d)) if c <= a && d >= b => true,
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for AssignmentPair {
+    type Err = TokenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b, c, d) = scan!("{}-{},{}-{}", s)?;
+        Ok(Self(Assignment(a, b), Assignment(c, d)))
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<AssignmentPair>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|e| ParseError::from_token(Day04::DAY, input, line, e))
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Answer<u32> {
+        let fully_overlapping_amount = parse(input)?
+            .iter()
+            .filter(|pair| pair.is_fully_overlapping())
+            .count();
+
+        Ok(Some(fully_overlapping_amount as u32))
+    }
+
+    fn part_two(input: &str) -> Answer<u32> {
+        let overlapping_amount = parse(input)?
+            .iter()
+            .filter(|pair| pair.is_overlapping())
+            .count();
+
+        Ok(Some(overlapping_amount as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(Day04::part_one(&input), Ok(Some(2)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(Day04::part_two(&input), Ok(Some(4)));
+    }
+
+    #[test]
+    fn test_parse_assignment_pair() {
+        assert_eq!(
+            "1-2,3-4".parse(),
+            Ok(AssignmentPair(Assignment(1, 2), Assignment(3, 4)))
+        );
+        assert!("1-2;3-4".parse::<AssignmentPair>().is_err());
+    }
+
+    #[test]
+    fn test_invalid_assignment() {
+        let error = Day04::part_one("2-4,6-8\n2-x,4-5").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn test_assignment_is_overlapping() {
+        assert!(AssignmentPair(Assignment(5, 7), Assignment(7, 9)).is_overlapping());
+
+        assert!(AssignmentPair(Assignment(2, 8), Assignment(3, 7)).is_overlapping());
+
+        assert!(AssignmentPair(Assignment(6, 6), Assignment(4, 6)).is_overlapping());
+
+        assert!(AssignmentPair(Assignment(2, 6), Assignment(4, 8)).is_overlapping());
+
+        assert!(!AssignmentPair(Assignment(1, 2), Assignment(3, 4)).is_overlapping());
+    }
+
+    #[test]
+    fn test_assignment_is_fully_overlapping() {
+        assert!(AssignmentPair(Assignment(2, 8), Assignment(3, 7)).is_fully_overlapping());
+
+        assert!(AssignmentPair(Assignment(6, 6), Assignment(4, 6)).is_fully_overlapping());
+
+        assert!(!AssignmentPair(Assignment(1, 2), Assignment(3, 4)).is_fully_overlapping());
+    }
+}
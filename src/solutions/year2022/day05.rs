@@ -0,0 +1,177 @@
+// This one is a mess
+
+use crate::helpers::parse::{self, TokenError};
+use crate::{scan, Answer, ParseError, Solution};
+use std::str::FromStr;
+
+#[derive(Debug)]
+struct Instruction {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = TokenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (amount, from, to) = scan!("move {} from {} to {}", s)?;
+        Ok(Instruction { amount, from, to })
+    }
+}
+
+/// Instructions with the line of the input each was read from.
+type Instructions<'a> = Vec<(&'a str, Instruction)>;
+
+/// Reads the drawing of the stacks, bottom crate first, and the instructions after it.
+fn parse(input: &str) -> Result<(Vec<Vec<char>>, Instructions<'_>), ParseError> {
+    let mut paragraphs = parse::paragraphs(input);
+    let end = &input[input.len()..];
+    let (Some(drawing), Some(instructions)) = (paragraphs.next(), paragraphs.next()) else {
+        return Err(ParseError::at(
+            Day05::DAY,
+            input,
+            end,
+            "a drawing of the stacks and instructions separated by a blank line",
+        ));
+    };
+
+    let mut rows = drawing.lines().rev();
+    let numbers = rows.next().unwrap_or_default();
+    let mut stacks = vec![Vec::new(); numbers.split_whitespace().count()];
+    for row in rows {
+        for (i, chunk) in row.as_bytes().chunks(4).enumerate() {
+            let c = chunk.get(1).copied().unwrap_or(b' ') as char;
+            if c == ' ' {
+                continue;
+            }
+            let Some(stack) = stacks.get_mut(i) else {
+                let start = i * 4;
+                return Err(ParseError::at(
+                    Day05::DAY,
+                    input,
+                    row.get(start..start + chunk.len()).unwrap_or(row),
+                    format!("at most {} stacks", numbers.split_whitespace().count()),
+                ));
+            };
+            stack.push(c);
+        }
+    }
+
+    let instructions = instructions
+        .lines()
+        .map(|line| {
+            let instruction: Instruction = line
+                .parse()
+                .map_err(|e| ParseError::from_token(Day05::DAY, input, line, e))?;
+            for stack in [instruction.from, instruction.to] {
+                if stack == 0 || stack > stacks.len() {
+                    return Err(ParseError::at(
+                        Day05::DAY,
+                        input,
+                        line,
+                        format!("stacks from 1 to {}", stacks.len()),
+                    ));
+                }
+            }
+            Ok((line, instruction))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((stacks, instructions))
+}
+
+/// Takes the top `amount` crates off stack `from`, the top crate last.
+/// `line` is the line of `input` with the instruction, to point at if the stack holds fewer crates.
+fn take_crates(
+    input: &str,
+    line: &str,
+    stacks: &mut [Vec<char>],
+    &Instruction { amount, from, .. }: &Instruction,
+) -> Result<Vec<char>, ParseError> {
+    let stack = &mut stacks[from - 1];
+    if amount > stack.len() {
+        return Err(ParseError::at(
+            Day05::DAY,
+            input,
+            line,
+            format!("at most {} crates on stack {from}", stack.len()),
+        ));
+    }
+    Ok(stack.split_off(stack.len() - amount))
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> String {
+    stacks
+        .iter()
+        .map(|stack| stack.last().copied().unwrap_or(' '))
+        .collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> Answer<String> {
+        let (mut stacks, instructions) = parse(input)?;
+
+        for (line, instruction) in instructions {
+            // crates are moved one at a time, so the top crate ends up at the bottom.
+            let moved = take_crates(input, line, &mut stacks, &instruction)?;
+            stacks[instruction.to - 1].extend(moved.into_iter().rev());
+        }
+
+        Ok(Some(top_crates(&stacks)))
+    }
+
+    fn part_two(input: &str) -> Answer<String> {
+        let (mut stacks, instructions) = parse(input)?;
+
+        for (line, instruction) in instructions {
+            let moved = take_crates(input, line, &mut stacks, &instruction)?;
+            stacks[instruction.to - 1].extend(moved);
+        }
+
+        Ok(Some(top_crates(&stacks)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
+        assert_eq!(Day05::part_one(input), Ok(Some("CMZ".to_string())));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
+        assert_eq!(Day05::part_two(input), Ok(Some("MCD".to_string())));
+    }
+
+    #[test]
+    fn test_invalid_instructions() {
+        let error = Day05::part_one("[A]\n 1 \n\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.expected, "stacks from 1 to 1");
+
+        let error = Day05::part_one("[A]\n 1 \n\nmove one from 1 to 1").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 6));
+
+        assert!(Day05::part_one("[A]\n 1 ").is_err());
+
+        let input = "[A]\n 1   2 \n\nmove 1 from 1 to 2\nmove 1 from 1 to 2";
+        let error = Day05::part_one(input).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.expected, "at most 0 crates on stack 1");
+
+        let error = Day05::part_two("[A]\n 1   2 \n\nmove 2 from 1 to 2").unwrap_err();
+        assert_eq!(error.expected, "at most 1 crates on stack 1");
+    }
+}
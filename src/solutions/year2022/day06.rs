@@ -0,0 +1,102 @@
+use crate::{Answer, ParseError, Solution};
+
+const ALPHABET_LENGTH: usize = b'z' as usize - b'a' as usize + 1;
+
+/// The position after the first `window_size` different characters, `None` if there is no such marker.
+fn process_message(input: &str, window_size: usize) -> Answer<u32> {
+    let signal = input.trim_end();
+    if let Some((i, c)) = signal.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+        return Err(ParseError::at(
+            Day06::DAY,
+            input,
+            &signal[i..i + c.len_utf8()],
+            "a letter from a to z",
+        ));
+    }
+
+    let marker = signal
+        .as_bytes()
+        .windows(window_size)
+        .enumerate()
+        .find(|(_, window)| {
+            let mut checked = [false; ALPHABET_LENGTH];
+
+            window.iter().all(|c| {
+                let c = (*c - b'a') as usize;
+                if checked[c] {
+                    false
+                } else {
+                    checked[c] = true;
+                    true
+                }
+            })
+        })
+        .map(|(index, _)| (index + window_size) as u32);
+
+    Ok(marker)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Answer<u32> {
+        process_message(input, 4)
+    }
+
+    fn part_two(input: &str) -> Answer<u32> {
+        process_message(input, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 6).unwrap();
+        assert_eq!(Day06::part_one(&input), Ok(Some(7)));
+
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(Day06::part_one(input), Ok(Some(5)));
+
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(Day06::part_one(input), Ok(Some(6)));
+
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(Day06::part_one(input), Ok(Some(10)));
+
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(Day06::part_one(input), Ok(Some(11)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 6).unwrap();
+        assert_eq!(Day06::part_two(&input), Ok(Some(19)));
+
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(Day06::part_two(input), Ok(Some(23)));
+
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(Day06::part_two(input), Ok(Some(23)));
+
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(Day06::part_two(input), Ok(Some(29)));
+
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(Day06::part_two(input), Ok(Some(26)));
+    }
+
+    #[test]
+    fn test_invalid_signal() {
+        assert_eq!(Day06::part_one("aaaa"), Ok(None));
+
+        let error = Day06::part_one("abcD").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+    }
+}
@@ -0,0 +1,201 @@
+// This one is also a mess
+
+use crate::{Answer, ParseError, Solution};
+
+const TOTAL_SPACE_AVAILABLE: u32 = 70000000;
+const SPACE_NEEDED_FOR_UPDATE: u32 = 30000000;
+
+#[derive(Debug)]
+struct File(u32);
+
+#[derive(Debug)]
+struct Directory<'a> {
+    name: &'a str,
+    items: Option<Vec<FSItem<'a>>>,
+}
+
+impl<'a> Directory<'a> {
+    fn new(name: &'a str) -> Self {
+        Self { name, items: None }
+    }
+}
+
+#[derive(Debug)]
+enum FSItem<'a> {
+    File(File),
+    Directory(Directory<'a>),
+}
+
+impl<'a> FSItem<'a> {
+    /// Reads `line`, a line of the output of `ls` in `input`.
+    fn parse(input: &str, line: &'a str) -> Result<Self, ParseError> {
+        match line.split_once(' ') {
+            Some(("dir", dir)) => Ok(Self::Directory(Directory::new(dir))),
+            Some((size, _)) => size
+                .parse()
+                .map(|size| Self::File(File(size)))
+                .map_err(|_| ParseError::at(Day07::DAY, input, size, "a file size or \"dir\"")),
+            None => Err(ParseError::at(
+                Day07::DAY,
+                input,
+                line,
+                "a directory or a file with its size",
+            )),
+        }
+    }
+}
+
+fn directory_at<'a, 'b>(item: &'b mut FSItem<'a>, path: &[usize]) -> &'b mut Directory<'a> {
+    let FSItem::Directory(dir) = item else {
+        unreachable!();
+    };
+
+    match path.split_first() {
+        None => dir,
+        Some((&index, rest)) => directory_at(&mut dir.items.as_mut().unwrap()[index], rest),
+    }
+}
+
+/// Replays the terminal output of `input` into the tree of directories below `/`.
+fn parse(input: &str) -> Result<FSItem<'_>, ParseError> {
+    let mut root = FSItem::Directory(Directory::new("/"));
+    // indices of the directories from the root to the current one.
+    let mut path: Vec<usize> = Vec::new();
+
+    for line in input.lines() {
+        let dir = directory_at(&mut root, &path);
+        match line.split_whitespace().collect::<Vec<_>>()[..] {
+            ["$", "cd", "/"] => path.clear(),
+            ["$", "cd", ".."] => {
+                path.pop();
+            }
+            ["$", "cd", name] => {
+                let index = dir
+                    .items
+                    .iter()
+                    .flatten()
+                    .position(|item| match item {
+                        FSItem::Directory(d) => d.name == name,
+                        FSItem::File(_) => false,
+                    })
+                    .ok_or_else(|| {
+                        ParseError::at(Day07::DAY, input, name, "a directory listed by ls")
+                    })?;
+
+                path.push(index);
+            }
+            ["$", "ls"] => {
+                dir.items.get_or_insert_with(Vec::new).clear();
+            }
+            ["$", ..] => {
+                return Err(ParseError::at(
+                    Day07::DAY,
+                    input,
+                    line,
+                    "a cd or ls command",
+                ))
+            }
+            _ => {
+                let item = FSItem::parse(input, line)?;
+                dir.items.get_or_insert_with(Vec::new).push(item);
+            }
+        }
+    }
+
+    Ok(root)
+}
+
+fn flatten_directories<'a>(item: &'a FSItem<'a>) -> Vec<&'a FSItem<'a>> {
+    match item {
+        FSItem::File(_) => Vec::new(),
+        FSItem::Directory(d) => {
+            let mut directories = Vec::new();
+            for item in d.items.iter().flatten() {
+                if let FSItem::Directory(_) = item {
+                    directories.push(item);
+                }
+                directories.extend(flatten_directories(item));
+            }
+            directories
+        }
+    }
+}
+
+fn calculate_directory_size(item: &FSItem) -> u32 {
+    match item {
+        FSItem::File(File(size)) => *size,
+        FSItem::Directory(d) => d
+            .items
+            .iter()
+            .flatten()
+            .map(|item| match item {
+                FSItem::File(File(size)) => *size,
+                FSItem::Directory(_) => calculate_directory_size(item),
+            })
+            .sum(),
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Answer<u32> {
+        let root = parse(input)?;
+        let directories = flatten_directories(&root);
+
+        Ok(Some(
+            directories
+                .into_iter()
+                .map(calculate_directory_size)
+                .filter(|size| *size <= 100000)
+                .sum(),
+        ))
+    }
+
+    fn part_two(input: &str) -> Answer<u32> {
+        let root = parse(input)?;
+        let directories = flatten_directories(&root);
+        let used_space = calculate_directory_size(&root);
+        let unused_space = TOTAL_SPACE_AVAILABLE.saturating_sub(used_space);
+        let needed_space = SPACE_NEEDED_FOR_UPDATE.saturating_sub(unused_space);
+
+        let mut sizes = directories
+            .into_iter()
+            .map(calculate_directory_size)
+            .collect::<Vec<u32>>();
+        sizes.sort();
+        Ok(sizes.into_iter().find(|size| *size > needed_space))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 7).unwrap();
+        assert_eq!(Day07::part_one(&input), Ok(Some(95437)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 7).unwrap();
+        assert_eq!(Day07::part_two(&input), Ok(Some(24933642)));
+    }
+
+    #[test]
+    fn test_invalid_output() {
+        let error = Day07::part_one("$ cd /\n$ ls\ndir a\n$ cd b  ").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 6));
+        assert_eq!(error.text, "b");
+
+        let error = Day07::part_one("$ ls\n12k b.txt").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "12k");
+    }
+}
@@ -0,0 +1,93 @@
+use crate::helpers::grid::{Grid, Position, NEIGHBORS4};
+use crate::{Answer, ParseError, Solution};
+
+fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10)).map_err(|e| ParseError::from_grid(Day08::DAY, input, e))
+}
+
+/// Number of trees seen from the tree at `position` when looking in the direction of `delta`.
+fn viewing_distance(grid: &Grid<u32>, position: Position, delta: (isize, isize)) -> u32 {
+    let tree = grid[position];
+    let mut distance = 0;
+    for (_, other_tree) in grid.ray(position, delta) {
+        distance += 1;
+        if *other_tree >= tree {
+            break;
+        }
+    }
+    distance
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Answer<u32> {
+        let grid = parse(input)?;
+
+        let visible = grid
+            .iter()
+            .filter(|(position, tree)| {
+                NEIGHBORS4.into_iter().any(|delta| {
+                    grid.ray(*position, delta)
+                        .all(|(_, other_tree)| *tree > other_tree)
+                })
+            })
+            .count();
+
+        Ok(Some(visible as u32))
+    }
+
+    fn part_two(input: &str) -> Answer<u32> {
+        let grid = parse(input)?;
+
+        Ok(grid
+            .positions()
+            .map(|position| {
+                NEIGHBORS4
+                    .into_iter()
+                    .map(|delta| viewing_distance(&grid, position, delta))
+                    .product()
+            })
+            .max())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 8).unwrap();
+        assert_eq!(Day08::part_one(&input), Ok(Some(21)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 8).unwrap();
+        assert_eq!(Day08::part_two(&input), Ok(Some(8)));
+    }
+
+    #[test]
+    fn test_invalid_grid() {
+        let error = Day08::part_one("303\n2x5").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "x");
+
+        let error = Day08::part_one("303\n25").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "");
+        assert_eq!(error.expected, "1 more cells");
+
+        let error = Day08::part_one("303\n25512").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(
+            error.to_string(),
+            "day 08, line 2, column 4: expected the end of the row after 3 cells, found \"12\"\n  |\n2 | 25512\n  |    ^^"
+        );
+    }
+}
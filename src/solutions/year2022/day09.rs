@@ -0,0 +1,208 @@
+use crate::helpers::parse::TokenError;
+use crate::helpers::point::{Direction, Point};
+use crate::{Answer, ParseError, Solution};
+use std::{collections::BTreeSet, str::FromStr};
+
+#[derive(Debug, Clone, Copy)]
+struct Motion {
+    direction: Direction,
+    steps: u32,
+}
+
+impl FromStr for Motion {
+    type Err = TokenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, steps) = s
+            .split_once(' ')
+            .ok_or_else(|| TokenError::at(s, s, "a direction and a number of steps"))?;
+        let steps = steps
+            .parse()
+            .map_err(|_| TokenError::at(s, steps, "a number of steps"))?;
+        let direction = match direction {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(TokenError::at(s, direction, "one of U, D, L or R")),
+        };
+        Ok(Self { direction, steps })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Knot {
+    position: Point,
+    positions: BTreeSet<Point>,
+}
+
+impl Knot {
+    fn new(position: Point) -> Self {
+        Self {
+            position,
+            positions: BTreeSet::from([position]),
+        }
+    }
+
+    fn move_to(&mut self, direction: Direction, steps: u32) {
+        self.position += direction.delta() * steps as i64;
+    }
+
+    /// Steps towards `head`, diagonally if needed, until touching it.
+    fn follow(&mut self, head: Point) {
+        while self.position.chebyshev(head) > 1 {
+            self.position += (head - self.position).signum();
+            self.positions.insert(self.position);
+        }
+    }
+}
+
+impl Default for Knot {
+    fn default() -> Self {
+        Self::new(Point::ORIGIN)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Motion>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|e| ParseError::from_token(Day09::DAY, input, line, e))
+        })
+        .collect()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Answer<u32> {
+        let motions = parse(input)?;
+        let mut head = Knot::default();
+        let mut tail = Knot::default();
+
+        for motion in motions {
+            head.move_to(motion.direction, motion.steps);
+            tail.follow(head.position);
+        }
+
+        Ok(Some(tail.positions.len() as u32))
+    }
+
+    fn part_two(input: &str) -> Answer<u32> {
+        let motions = parse(input)?;
+        let mut knots: Vec<Knot> = (0..10).map(|_| Knot::default()).collect();
+
+        for motion in motions {
+            for _ in 0..motion.steps {
+                knots[0].move_to(motion.direction, 1);
+                for i in 1..knots.len() {
+                    let head = knots[i - 1].position;
+                    knots[i].follow(head);
+                }
+            }
+        }
+
+        Ok(Some(knots.last().unwrap().positions.len() as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 9).unwrap();
+        assert_eq!(Day09::part_one(&input), Ok(Some(13)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 9).unwrap();
+        assert_eq!(Day09::part_two(&input), Ok(Some(1)));
+        let input = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20";
+        assert_eq!(Day09::part_two(input), Ok(Some(36)));
+    }
+
+    #[test]
+    fn test_invalid_motion() {
+        let error = Day09::part_one("R 4\nX 4").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "one of U, D, L or R");
+
+        let error = Day09::part_one("R 4\nU -4").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_move() {
+        let mut head = Knot::default();
+        head.move_to(Direction::Up, 5);
+        assert_eq!(head.position, Point::new(0, -5));
+
+        head.move_to(Direction::Right, 5);
+        assert_eq!(head.position, Point::new(5, -5));
+
+        head.move_to(Direction::Down, 5);
+        assert_eq!(head.position, Point::new(5, 0));
+
+        head.move_to(Direction::Left, 5);
+        assert_eq!(head.position, Point::new(0, 0));
+    }
+
+    #[test]
+    fn test_follow_axis() {
+        let mut head = Knot::default();
+        let mut tail = Knot::default();
+        head.move_to(Direction::Up, 5);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(0, -4));
+        head.move_to(Direction::Down, 5);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(0, -1));
+
+        let mut head = Knot::default();
+        let mut tail = Knot::default();
+        head.move_to(Direction::Right, 5);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(4, 0));
+        head.move_to(Direction::Left, 5);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(1, 0));
+    }
+
+    #[test]
+    fn test_follow_diagonal() {
+        let mut head = Knot::default();
+        let mut tail = Knot::default();
+        head.move_to(Direction::Up, 5);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(0, -4));
+        head.move_to(Direction::Right, 5);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(4, -5));
+        head.move_to(Direction::Down, 5);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(5, -1));
+        head.move_to(Direction::Left, 5);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(1, 0));
+    }
+
+    #[test]
+    fn test_ignore_same_place() {
+        let mut head = Knot::default();
+        let mut tail = Knot::default();
+        head.move_to(Direction::Up, 5);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(0, -4));
+        head.move_to(Direction::Down, 1);
+        tail.follow(head.position);
+        assert_eq!(tail.position, Point::new(0, -4));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::helpers::ocr;
+use crate::helpers::parse::TokenError;
+use crate::{Answer, ParseError, Solution};
+use std::str::FromStr;
+
+enum Operation {
+    NoOp,
+    AddX(i32),
+}
+
+impl FromStr for Operation {
+    type Err = TokenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut op = s.split(' ');
+        let op = (op.next(), op.next());
+
+        match op {
+            (Some("noop"), _) => Ok(Operation::NoOp),
+            (Some("addx"), Some(value)) => {
+                let value = value
+                    .parse()
+                    .map_err(|_| TokenError::at(s, value, "a value to add to X"))?;
+                Ok(Operation::AddX(value))
+            }
+            _ => Err(TokenError::at(s, s, "\"noop\" or \"addx\" with a value")),
+        }
+    }
+}
+
+struct Cpu {
+    register_x: i32,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Self { register_x: 1 }
+    }
+
+    fn cycle(&mut self, operation: &Operation) -> i32 {
+        let x = self.register_x;
+
+        match operation {
+            Operation::NoOp => x,
+            Operation::AddX(value) => {
+                self.register_x += value;
+                x
+            }
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Operation>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|e| ParseError::from_token(Day10::DAY, input, line, e))
+        })
+        .collect()
+}
+
+/// The operation running during each cycle: `addx` takes two cycles and only applies in its second one.
+fn cycles(operations: &[Operation]) -> impl Iterator<Item = &Operation> {
+    operations.iter().flat_map(|op| match op {
+        Operation::NoOp => vec![op],
+        Operation::AddX(_) => vec![&Operation::NoOp, op],
+    })
+}
+
+/// The picture drawn by the CRT, one line per row of 40 pixels.
+fn draw_crt(input: &str) -> Result<String, ParseError> {
+    let mut cpu = Cpu::new();
+    let operations = parse(input)?;
+    let crt_drawing = cycles(&operations)
+        .enumerate()
+        .map(|(i, op)| {
+            let register_x = cpu.cycle(op);
+
+            let i = i % 40;
+
+            let char = if i as i32 >= register_x - 1 && i as i32 <= register_x + 1 {
+                "#".to_string()
+            } else {
+                ".".to_string()
+            };
+
+            if i == 39 {
+                char + "\n"
+            } else {
+                char
+            }
+        })
+        .collect::<String>();
+
+    Ok(crt_drawing.trim().to_string())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> Answer<i32> {
+        let mut cpu = Cpu::new();
+        let operations = parse(input)?;
+        let signal_strength_cycles = cycles(&operations)
+            .map(|op| cpu.cycle(op))
+            .enumerate()
+            .filter_map(|(i, value)| {
+                if (i + 1) % 40 == 20 {
+                    Some(value * (i + 1) as i32)
+                } else {
+                    None
+                }
+            });
+
+        Ok(Some(signal_strength_cycles.sum()))
+    }
+
+    fn part_two(input: &str) -> Answer<String> {
+        let crt_drawing = draw_crt(input)?;
+        if crate::debug_enabled() {
+            eprintln!("{crt_drawing}");
+        }
+
+        Ok(ocr::parse_letters(&crt_drawing).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 10).unwrap();
+        assert_eq!(Day10::part_one(&input), Ok(Some(13140)));
+    }
+
+    #[test]
+    fn test_draw_crt() {
+        let input = crate::read_file("examples", 2022, 10).unwrap();
+        let expected = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+            .to_string();
+        assert_eq!(draw_crt(&input), Ok(expected));
+    }
+
+    #[test]
+    fn test_part_two() {
+        // the example draws a pattern instead of letters.
+        let input = crate::read_file("examples", 2022, 10).unwrap();
+        assert_eq!(Day10::part_two(&input), Ok(None));
+    }
+
+    #[test]
+    fn test_invalid_operation() {
+        let error = Day10::part_one("noop\naddx x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+
+        assert!(Day10::part_two("noop\nmul 2").is_err());
+    }
+}
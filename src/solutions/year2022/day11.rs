@@ -0,0 +1,373 @@
+use crate::helpers::math;
+use crate::helpers::parse::{self, TokenError};
+use crate::{scan, Answer, ParseError, Solution};
+use std::str::FromStr;
+
+struct Item {
+    monkey: usize,
+    worry_level: u64,
+}
+
+impl Item {
+    fn new(monkey: usize, worry_level: u64) -> Self {
+        Self {
+            monkey,
+            worry_level,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    MultiplySelf,
+}
+
+impl FromStr for Operation {
+    type Err = TokenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((_, expression)) = s.split_once("Operation: new = old") else {
+            return Err(TokenError::at(s, s.trim(), "\"Operation: new = old\""));
+        };
+        let mut parts = expression.split_whitespace();
+        let mut next_part = || parts.next().unwrap_or(&s[s.len()..]);
+        let (operator, value) = (next_part(), next_part());
+        let parse_value = || {
+            value
+                .parse::<u64>()
+                .map_err(|_| TokenError::at(s, value, "a number or \"old\""))
+        };
+        match operator {
+            "*" if value == "old" => Ok(Self::MultiplySelf),
+            "+" => Ok(Self::Add(parse_value()?)),
+            "*" => Ok(Self::Multiply(parse_value()?)),
+            _ => Err(TokenError::at(s, operator, "\"+\" or \"*\"")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Test {
+    divisible_by: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+#[derive(Debug)]
+struct Monkey {
+    number: usize,
+    starting_items: Vec<u64>,
+    operation: Operation,
+    test: Test,
+    monkey_business_level: u32,
+}
+
+impl Monkey {
+    fn new(number: usize, starting_items: Vec<u64>, operation: Operation, test: Test) -> Self {
+        Self {
+            number,
+            starting_items,
+            operation,
+            test,
+            monkey_business_level: 0,
+        }
+    }
+
+    /// Inspects the items this monkey holds. If there is a `modulus`, worry levels are kept below it.
+    fn round(&mut self, items: &mut Vec<Item>, worry_divider: u64, modulus: Option<u64>) {
+        for item in items {
+            if item.monkey != self.number {
+                continue;
+            }
+
+            self.monkey_business_level += 1;
+
+            let worry_level = item.worry_level;
+
+            let add = |a, b| match modulus {
+                Some(modulus) => ((a as u128 + b as u128) % modulus as u128) as u64,
+                None => a + b,
+            };
+            let multiply = |a, b| match modulus {
+                Some(modulus) => math::mul_mod(a, b, modulus),
+                None => a * b,
+            };
+            let worry_level = match self.operation {
+                Operation::Add(value) => add(worry_level, value),
+                Operation::Multiply(value) => multiply(worry_level, value),
+                Operation::MultiplySelf => multiply(worry_level, worry_level),
+            };
+
+            let worry_level = worry_level / worry_divider;
+
+            item.worry_level = worry_level;
+
+            let Test {
+                divisible_by,
+                if_true,
+                if_false,
+            } = self.test;
+
+            if worry_level.is_multiple_of(divisible_by) {
+                item.monkey = if_true;
+            } else {
+                item.monkey = if_false;
+            }
+        }
+    }
+}
+
+/// The text of the numbers of a monkey that are checked against the other monkeys.
+struct Tokens<'a> {
+    number: &'a str,
+    divisor: &'a str,
+    if_true: &'a str,
+    if_false: &'a str,
+}
+
+/// The last word of `line`, e.g. the number of `Monkey 0:`.
+fn last_word(line: &str) -> &str {
+    let line = line.trim_end().trim_end_matches(':');
+    line.rsplit(' ').next().unwrap_or(line)
+}
+
+/// Reads one monkey, a paragraph of `input`, with the text of its numbers.
+fn parse_monkey<'a>(input: &str, paragraph: &'a str) -> Result<(Monkey, Tokens<'a>), ParseError> {
+    let mut lines = paragraph.lines();
+    let mut next_line = || lines.next().unwrap_or(&paragraph[paragraph.len()..]);
+    let located = |line| move |e| ParseError::from_token(Day11::DAY, input, line, e);
+
+    let line = next_line();
+    let (number,) = scan!("Monkey {}:", line).map_err(located(line))?;
+    let number_token = last_word(line);
+
+    let line = next_line();
+    let Some((_, items)) = line.split_once("Starting items:") else {
+        return Err(ParseError::at(
+            Day11::DAY,
+            input,
+            line,
+            "\"Starting items:\"",
+        ));
+    };
+    let starting_items = parse::ints(items).map_err(|e| {
+        let mut error = located(line)(e);
+        error.column += line.len() - items.len();
+        error
+    })?;
+
+    let line = next_line();
+    let operation = line.parse().map_err(located(line))?;
+
+    let line = next_line();
+    let (divisible_by,) = scan!("Test: divisible by {}", line).map_err(located(line))?;
+    let divisor = last_word(line);
+    if divisible_by == 0 {
+        return Err(ParseError::at(
+            Day11::DAY,
+            input,
+            divisor,
+            "a divisor other than 0",
+        ));
+    }
+    let line = next_line();
+    let (if_true,) = scan!("If true: throw to monkey {}", line).map_err(located(line))?;
+    let if_true_token = last_word(line);
+    let line = next_line();
+    let (if_false,) = scan!("If false: throw to monkey {}", line).map_err(located(line))?;
+    let if_false_token = last_word(line);
+
+    let test = Test {
+        divisible_by,
+        if_true,
+        if_false,
+    };
+    let tokens = Tokens {
+        number: number_token,
+        divisor,
+        if_true: if_true_token,
+        if_false: if_false_token,
+    };
+    Ok((Monkey::new(number, starting_items, operation, test), tokens))
+}
+
+/// The text of the divisor of every monkey.
+type Divisors<'a> = Vec<&'a str>;
+
+/// The monkeys, the items they start with and the text of their divisors.
+fn parse(input: &str) -> Result<(Vec<Monkey>, Vec<Item>, Divisors<'_>), ParseError> {
+    let mut monkeys = Vec::new();
+    let mut tokens = Vec::new();
+    for paragraph in parse::paragraphs(input) {
+        let (monkey, monkey_tokens) = parse_monkey(input, paragraph)?;
+        // items are handed to monkeys by position, so the numbers have to count up from 0.
+        if monkey.number != monkeys.len() {
+            return Err(ParseError::at(
+                Day11::DAY,
+                input,
+                monkey_tokens.number,
+                format!("monkey {}", monkeys.len()),
+            ));
+        }
+        monkeys.push(monkey);
+        tokens.push(monkey_tokens);
+    }
+
+    for (monkey, tokens) in monkeys.iter().zip(&tokens) {
+        for (target, token) in [
+            (monkey.test.if_true, tokens.if_true),
+            (monkey.test.if_false, tokens.if_false),
+        ] {
+            if target >= monkeys.len() {
+                return Err(ParseError::at(
+                    Day11::DAY,
+                    input,
+                    token,
+                    format!("a monkey below {} to throw to", monkeys.len()),
+                ));
+            }
+        }
+    }
+
+    let items = monkeys
+        .iter()
+        .enumerate()
+        .flat_map(|(i, monkey)| {
+            monkey
+                .starting_items
+                .iter()
+                .map(move |item| Item::new(i, *item))
+        })
+        .collect();
+
+    let divisors = tokens.iter().map(|tokens| tokens.divisor).collect();
+    Ok((monkeys, items, divisors))
+}
+
+/// The lcm of the divisors of all monkeys, `divisors` is their text in `input`.
+fn modulus(input: &str, monkeys: &[Monkey], divisors: &[&str]) -> Result<u64, ParseError> {
+    monkeys
+        .iter()
+        .zip(divisors)
+        .try_fold(1, |modulus, (monkey, divisor)| {
+            math::lcm(modulus, monkey.test.divisible_by).ok_or_else(|| {
+                ParseError::at(
+                    Day11::DAY,
+                    input,
+                    divisor,
+                    format!("divisors with a least common multiple up to {}", u64::MAX),
+                )
+            })
+        })
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn part_one(input: &str) -> Answer<u32> {
+        let (mut monkeys, mut items, _) = parse(input)?;
+
+        for _ in 0..20 {
+            for monkey in &mut monkeys {
+                // dividing by 3 does not preserve remainders, so the levels are kept as they are.
+                monkey.round(&mut items, 3, None);
+            }
+        }
+
+        monkeys.sort_by_key(|monkey| monkey.monkey_business_level);
+
+        Ok(Some(
+            monkeys
+                .iter()
+                .rev()
+                .take(2)
+                .map(|monkey| monkey.monkey_business_level)
+                .product(),
+        ))
+    }
+
+    // Couldn't figure out what I had to do for this one so I watched this
+    // https://www.youtube.com/watch?v=0RkTrYDyzmE&t=2659s
+    fn part_two(input: &str) -> Answer<u64> {
+        // every test only depends on the level modulo its divisor, so levels can be reduced modulo their lcm.
+        let (mut monkeys, mut items, divisors) = parse(input)?;
+        let modulus = modulus(input, &monkeys, &divisors)?;
+
+        for _ in 0..10000 {
+            for monkey in &mut monkeys {
+                monkey.round(&mut items, 1, Some(modulus));
+            }
+        }
+
+        monkeys.sort_by_key(|monkey| monkey.monkey_business_level);
+
+        Ok(Some(
+            monkeys
+                .iter()
+                .rev()
+                .take(2)
+                .map(|monkey| monkey.monkey_business_level as u64)
+                .product(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 11).unwrap();
+        assert_eq!(Day11::part_one(&input), Ok(Some(10_605)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 11).unwrap();
+        assert_eq!(Day11::part_two(&input), Ok(Some(2_713_310_158)));
+    }
+
+    #[test]
+    fn test_invalid_monkey() {
+        let input = crate::read_file("examples", 2022, 11).unwrap();
+        let broken = input.replacen("new = old * 19", "new = old / 19", 1);
+        let error = Day11::part_one(&broken).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 24));
+        assert_eq!(error.text, "/");
+
+        let broken = input.replacen("79, 98", "79, -98", 1);
+        let error = Day11::part_one(&broken).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 23));
+
+        let broken = input.replacen("divisible by 23", "divisible by 0", 1);
+        assert!(Day11::part_two(&broken).is_err());
+
+        let broken = input.replacen("divisible by 13", "divisible by 18446744073709551557", 1);
+        let error = Day11::part_two(&broken).unwrap_err();
+        assert_eq!((error.line, error.column), (18, 22));
+        assert_eq!(error.text, "18446744073709551557");
+        // part one never reduces the levels, so it doesn't need the lcm.
+        assert!(Day11::part_one(&broken).is_ok());
+
+        // added values are reduced like products, so a large one can't overflow the level.
+        let broken = input.replacen("old + 6", &format!("old + {}", u64::MAX), 1);
+        assert!(Day11::part_two(&broken).is_ok());
+
+        let broken = input.replacen("throw to monkey 2", "throw to monkey 7", 1);
+        let error = Day11::part_one(&broken).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 30));
+        assert_eq!(error.text, "7");
+
+        let broken = input.replacen("Monkey 1:", "Monkey 5:", 1);
+        let error = Day11::part_one(&broken).unwrap_err();
+        assert_eq!((error.line, error.column), (8, 8));
+        assert_eq!(error.text, "5");
+    }
+}
@@ -0,0 +1,361 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub const ATTEMPTS_HEADER: &str = "timestamp,part,answer,outcome";
+
+pub fn get_attempts_path(year: u16, day: u8) -> String {
+    format!("src/attempts/{year}/{day:02}.csv")
+}
+
+/// Response of the server to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about the direction.
+    Incorrect,
+    /// Submitted too recently, retry after this many seconds.
+    Wait(u64),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too_high"),
+            Outcome::TooLow => write!(f, "too_low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::Wait(seconds) => write!(f, "wait_{seconds}s"),
+            Outcome::WrongLevel => write!(f, "wrong_level"),
+            Outcome::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too_high" => Ok(Outcome::TooHigh),
+            "too_low" => Ok(Outcome::TooLow),
+            "incorrect" => Ok(Outcome::Incorrect),
+            "wrong_level" => Ok(Outcome::WrongLevel),
+            "unknown" => Ok(Outcome::Unknown),
+            s => s
+                .strip_prefix("wait_")
+                .and_then(|s| s.strip_suffix('s'))
+                .and_then(|s| s.parse().ok())
+                .map(Outcome::Wait)
+                .ok_or(()),
+        }
+    }
+}
+
+/// Classifies the html page returned after submitting an answer.
+pub fn parse_response(html: &str) -> Outcome {
+    if html.contains("That's the right answer") {
+        Outcome::Correct
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if html.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else if html.contains("You gave an answer too recently") {
+        Outcome::Wait(parse_wait(html).unwrap_or(60))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown
+    }
+}
+
+/// Parses "You have 4m 32s left to wait." into seconds.
+fn parse_wait(html: &str) -> Option<u64> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = html[start..].find(" left to wait")?;
+    html[start..start + end]
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.len() - 1);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+/// A logged submission of an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub timestamp: u64,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Attempt {
+    pub fn new(part: u8, answer: &str, outcome: Outcome) -> Self {
+        Self {
+            timestamp: now(),
+            part,
+            answer: answer.to_string(),
+            outcome,
+        }
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(3, ',');
+        let timestamp = fields.next()?.parse().ok()?;
+        let part = fields.next()?.parse().ok()?;
+        // answers may contain commas, the outcome never does.
+        let (answer, outcome) = fields.next()?.rsplit_once(',')?;
+        Some(Self {
+            timestamp,
+            part,
+            answer: answer.to_string(),
+            outcome: outcome.parse().ok()?,
+        })
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            self.timestamp, self.part, self.answer, self.outcome
+        )
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Reads all attempts from a log file. A missing file is an empty log.
+pub fn load_attempts(path: &Path) -> io::Result<Vec<Attempt>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents.lines().filter_map(Attempt::parse).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn append_attempt(path: &Path, attempt: &Attempt) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if is_new {
+        writeln!(file, "{ATTEMPTS_HEADER}")?;
+    }
+
+    writeln!(file, "{}", attempt.to_csv())
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong(Outcome),
+    NotBelow(i64),
+    NotAbove(i64),
+    Wait(u64),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with {answer:?}.")
+            }
+            Refusal::KnownWrong(outcome) => {
+                write!(f, "this answer was already submitted ({outcome}).")
+            }
+            Refusal::NotBelow(high) => {
+                write!(f, "{high} was too high, the answer has to be lower.")
+            }
+            Refusal::NotAbove(low) => {
+                write!(f, "{low} was too low, the answer has to be higher.")
+            }
+            Refusal::Wait(seconds) => {
+                write!(f, "the server asked to wait another {seconds}s.")
+            }
+        }
+    }
+}
+
+/// Checks an answer against the previous `attempts` of `part` at unix time `now`.
+pub fn check_attempt(
+    attempts: &[Attempt],
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<(), Refusal> {
+    let attempts = attempts.iter().filter(|a| a.part == part);
+    let numeric = answer.parse::<i64>().ok();
+
+    let mut lowest_too_high: Option<i64> = None;
+    let mut highest_too_low: Option<i64> = None;
+
+    for attempt in attempts {
+        match attempt.outcome {
+            Outcome::Correct => return Err(Refusal::AlreadySolved(attempt.answer.clone())),
+            outcome if outcome.is_wrong() && attempt.answer == answer => {
+                return Err(Refusal::KnownWrong(outcome))
+            }
+            Outcome::Wait(seconds) if attempt.timestamp + seconds > now => {
+                return Err(Refusal::Wait(attempt.timestamp + seconds - now))
+            }
+            Outcome::TooHigh => {
+                if let Ok(value) = attempt.answer.parse::<i64>() {
+                    lowest_too_high = Some(lowest_too_high.map_or(value, |v| v.min(value)));
+                }
+            }
+            Outcome::TooLow => {
+                if let Ok(value) = attempt.answer.parse::<i64>() {
+                    highest_too_low = Some(highest_too_low.map_or(value, |v| v.max(value)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(answer) = numeric {
+        if let Some(high) = lowest_too_high.filter(|high| answer >= *high) {
+            return Err(Refusal::NotBelow(high));
+        }
+        if let Some(low) = highest_too_low.filter(|low| answer <= *low) {
+            return Err(Refusal::NotAbove(low));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(timestamp: u64, part: u8, answer: &str, outcome: Outcome) -> Attempt {
+        Attempt {
+            timestamp,
+            part,
+            answer: answer.into(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response("<p>That's the right answer! You are one gold star closer.</p>"),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too high.</p>"),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too low.</p>"),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer. If you're stuck...</p>"),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            parse_response("<p>You gave an answer too recently. You have 4m 32s left to wait.</p>"),
+            Outcome::Wait(272)
+        );
+        assert_eq!(
+            parse_response("<p>You don't seem to be solving the right level.</p>"),
+            Outcome::WrongLevel
+        );
+        assert_eq!(parse_response("<p>Hm?</p>"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_attempt_roundtrip() {
+        let attempt = attempt(1670000000, 2, "1,2", Outcome::Wait(30));
+        assert_eq!(Attempt::parse(&attempt.to_csv()), Some(attempt));
+        assert_eq!(Attempt::parse(ATTEMPTS_HEADER), None);
+    }
+
+    #[test]
+    fn test_check_attempt() {
+        let attempts = [
+            attempt(0, 1, "100", Outcome::TooHigh),
+            attempt(0, 1, "150", Outcome::TooHigh),
+            attempt(0, 1, "20", Outcome::TooLow),
+            attempt(0, 1, "ABC", Outcome::Incorrect),
+            attempt(0, 2, "7", Outcome::Correct),
+        ];
+
+        assert_eq!(check_attempt(&attempts, 1, "50", 0), Ok(()));
+        assert_eq!(
+            check_attempt(&attempts, 1, "ABC", 0),
+            Err(Refusal::KnownWrong(Outcome::Incorrect))
+        );
+        assert_eq!(
+            check_attempt(&attempts, 1, "120", 0),
+            Err(Refusal::NotBelow(100))
+        );
+        assert_eq!(
+            check_attempt(&attempts, 1, "20", 0),
+            Err(Refusal::KnownWrong(Outcome::TooLow))
+        );
+        assert_eq!(
+            check_attempt(&attempts, 1, "5", 0),
+            Err(Refusal::NotAbove(20))
+        );
+        assert_eq!(
+            check_attempt(&attempts, 2, "8", 0),
+            Err(Refusal::AlreadySolved("7".into()))
+        );
+    }
+
+    #[test]
+    fn test_check_attempt_wait() {
+        let attempts = [attempt(1000, 1, "1", Outcome::Wait(60))];
+        assert_eq!(
+            check_attempt(&attempts, 1, "2", 1030),
+            Err(Refusal::Wait(30))
+        );
+        assert_eq!(check_attempt(&attempts, 1, "2", 1060), Ok(()));
+    }
+}
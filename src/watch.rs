@@ -0,0 +1,121 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// How often `cargo watch-day` checks the watched files for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Files that affect the result of a day: its solution, the shared helpers, its input and its example.
+pub fn watched_paths(year: u16, day: u8) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = vec![
+        format!("src/solutions/year{year}/day{day:02}.rs").into(),
+        "src/helpers.rs".into(),
+        format!("src/inputs/{year}/{day:02}.txt").into(),
+        format!("src/examples/{year}/{day:02}.txt").into(),
+    ];
+    // helper modules in `src/helpers/` that exist when watching starts.
+    if let Ok(entries) = fs::read_dir("src/helpers") {
+        let mut helpers: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+        helpers.sort();
+        paths.extend(helpers);
+    }
+    paths
+}
+
+/// Detects changes to a set of files by polling their modification times.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        Self {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let modified = modified(&path);
+                    (path, modified)
+                })
+                .collect(),
+        }
+    }
+
+    /// Paths that were modified, created or deleted since the last call.
+    pub fn changed(&mut self) -> Vec<&Path> {
+        let mut changed = Vec::new();
+        for (path, last_modified) in &mut self.files {
+            let modified = modified(path);
+            if modified != *last_modified {
+                *last_modified = modified;
+                changed.push(path.as_path());
+            }
+        }
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Totals of the `test result:` lines printed by `cargo test`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TestSummary {
+    pub passed: u32,
+    pub failed: u32,
+}
+
+/// Sums the results of all test binaries in the output of `cargo test`.
+/// `None` if no tests ran, e.g. because the code did not compile.
+pub fn parse_test_summary(output: &str) -> Option<TestSummary> {
+    let mut summary: Option<TestSummary> = None;
+
+    for line in output.lines() {
+        let Some(result) = line.strip_prefix("test result: ") else {
+            continue;
+        };
+        let summary = summary.get_or_insert_with(TestSummary::default);
+
+        for field in result.split([';', '.']) {
+            match field.trim().split_once(' ') {
+                Some((count, "passed")) => summary.passed += count.parse().unwrap_or(0),
+                Some((count, "failed")) => summary.failed += count.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_test_summary() {
+        let output = "
+running 2 tests
+..
+test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 61 filtered out; finished in 0.00s
+
+running 1 test
+F
+test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s
+";
+        assert_eq!(
+            parse_test_summary(output),
+            Some(TestSummary {
+                passed: 2,
+                failed: 1
+            })
+        );
+        assert_eq!(parse_test_summary("error[E0308]: mismatched types"), None);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{Answer, Solution};
+
+pub struct Day{day_padded};
+
+impl Solution for Day{day_padded} {
+    const DAY: u8 = {day};
+    type PartOne = {answer_type};
+    type PartTwo = {answer_type};
+
+    fn part_one(input: &str) -> Answer<{answer_type}> {
+        Ok(None)
+    }
+
+    fn part_two(input: &str) -> Answer<{answer_type}> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", {year}, {day}).unwrap();
+        assert_eq!(Day{day_padded}::part_one(&input), Ok(None));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", {year}, {day}).unwrap();
+        assert_eq!(Day{day_padded}::part_two(&input), Ok(None));
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day{day_padded};
+
+struct Puzzle {
+    lines: Vec<String>,
+}
+
+fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Ok(Puzzle {
+        lines: input.lines().map(String::from).collect(),
+    })
+}
+
+impl Solution for Day{day_padded} {
+    const DAY: u8 = {day};
+    type PartOne = {answer_type};
+    type PartTwo = {answer_type};
+
+    fn part_one(input: &str) -> Answer<{answer_type}> {
+        let puzzle = parse(input)?;
+        Ok(None)
+    }
+
+    fn part_two(input: &str) -> Answer<{answer_type}> {
+        let puzzle = parse(input)?;
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = crate::read_file("examples", {year}, {day}).unwrap();
+        assert!(!parse(&input).unwrap().lines.is_empty());
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", {year}, {day}).unwrap();
+        assert_eq!(Day{day_padded}::part_one(&input), Ok(None));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", {year}, {day}).unwrap();
+        assert_eq!(Day{day_padded}::part_two(&input), Ok(None));
+    }
+}